@@ -0,0 +1,206 @@
+//! Benchmarks of the search for the shortest paths between hexagrams.
+//!
+//! Run them with `cargo bench`. The search for random sequences is also measured with the search
+//! the crate used before hexagrams were stored as bits, so a single run shows the speedup.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use iching::{
+    find_min_random_sequence, king_wen, seeded_rng, DistanceMatrix, Hexagram, HexagramBits,
+    HexagramSearcher, Path, SearchOperation, DISTANCE_MATRIX,
+};
+use rand::seq::SliceRandom;
+use std::collections::VecDeque;
+
+/// Benchmarks applying every search operation to every hexagram, through the full hexagrams, the
+/// bits of their lines, and the transformations of [`Hexagram`] that look the lines up in
+/// [`HEXAGRAM_INDEX`](iching::HEXAGRAM_INDEX).
+fn operations(c: &mut Criterion) {
+    let hexagrams: Vec<Hexagram> = (1..=64)
+        .map(Hexagram::from_number)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let ops = SearchOperation::all_operations();
+
+    c.bench_function("operations/apply", |b| {
+        b.iter(|| {
+            for hexagram in &hexagrams {
+                for operation in &ops {
+                    black_box(operation.apply(black_box(hexagram)));
+                }
+            }
+        })
+    });
+
+    let bits: Vec<HexagramBits> = hexagrams.iter().map(HexagramBits::from).collect();
+    c.bench_function("operations/apply_bits", |b| {
+        b.iter(|| {
+            for hexagram in &bits {
+                for operation in &ops {
+                    black_box(operation.apply_bits(black_box(*hexagram)));
+                }
+            }
+        })
+    });
+
+    c.bench_function("operations/index_lookup", |b| {
+        b.iter(|| {
+            for hexagram in &hexagrams {
+                let hexagram = black_box(hexagram);
+                black_box(hexagram.inverse());
+                black_box(hexagram.reverse());
+                black_box(hexagram.flip_trigrams());
+                black_box(hexagram.mirror_trigrams());
+                black_box(hexagram.use_nuclear_trigrams());
+                black_box(hexagram.mix_trigrams_bottom_first());
+            }
+        })
+    });
+}
+
+/// Benchmarks the search for the shortest paths between a few pairs of hexagrams, from the
+/// nearest to the farthest apart.
+fn shortest_paths(c: &mut Criterion) {
+    for (start, end) in [(1, 2), (3, 50), (11, 64), (1, 64)] {
+        let searcher = HexagramSearcher::new(start, end).unwrap();
+        c.bench_function(&format!("shortest_paths/{}-{}", start, end), |b| {
+            b.iter(|| black_box(searcher.find_shortest_paths(false)))
+        });
+    }
+
+    // All the shortest paths between the farthest hexagrams, built or only counted.
+    let searcher = HexagramSearcher::new(1, 64).unwrap();
+    c.bench_function("shortest_paths/all/1-64", |b| {
+        b.iter(|| black_box(searcher.find_shortest_paths(true)))
+    });
+    c.bench_function("shortest_paths/count/1-64", |b| {
+        b.iter(|| black_box(searcher.count_shortest_paths(true)))
+    });
+}
+
+/// Applies the operation with the transformations of [`Hexagram`], which look the resulting lines
+/// up in [`HEXAGRAM_INDEX`](iching::HEXAGRAM_INDEX), as the search did before it used bits.
+fn apply_with_index(operation: &SearchOperation, hexagram: &Hexagram) -> Hexagram {
+    match operation {
+        SearchOperation::NoOp => *hexagram,
+        SearchOperation::InverseLine(line) => hexagram.inverse_line(*line),
+        SearchOperation::InverseBottomTrigram => hexagram.inverse_bottom_trigram(),
+        SearchOperation::InverseTopTrigram => hexagram.inverse_top_trigram(),
+        SearchOperation::ReverseBottomTrigram => hexagram.reverse_bottom_trigram(),
+        SearchOperation::ReverseTopTrigram => hexagram.reverse_top_trigram(),
+        SearchOperation::FlipTrigrams => hexagram.flip_trigrams(),
+        SearchOperation::MirrorTrigrams => hexagram.mirror_trigrams(),
+        SearchOperation::NuclearTrigrams => hexagram.use_nuclear_trigrams(),
+        SearchOperation::InverseHexagram => hexagram.inverse(),
+        SearchOperation::ReverseHexagram => hexagram.reverse(),
+        SearchOperation::MixTrigramsBottomFirst => hexagram.mix_trigrams_bottom_first(),
+        SearchOperation::MixTrigramsTopFirst => hexagram.mix_trigrams_top_first(),
+    }
+}
+
+/// Returns the number of operations of the shortest paths between two different hexagrams, found
+/// with the breadth-first search that cloned every path into the queue, as the searcher did before
+/// it built a graph of the shortest paths.
+fn baseline_ops(start: &Hexagram, end: &Hexagram) -> usize {
+    let ops = SearchOperation::all_operations();
+    let mut queue: VecDeque<Path> = VecDeque::new();
+    queue.push_back(vec![(*start, SearchOperation::NoOp)]);
+    let mut shortest_paths: Vec<Path> = vec![];
+    while let Some(path) = queue.pop_front() {
+        if !shortest_paths.is_empty() && path.len() >= shortest_paths[0].len() {
+            break;
+        }
+        let (current_hexagram, _) = path.last().unwrap();
+        for operation in &ops {
+            let new_hexagram = apply_with_index(operation, current_hexagram);
+            if path.iter().any(|(h, _)| h == &new_hexagram) {
+                continue;
+            }
+            let mut new_path = path.clone();
+            new_path.push((new_hexagram, *operation));
+            if new_hexagram == *end {
+                shortest_paths.push(new_path);
+            } else {
+                queue.push_back(new_path);
+            }
+        }
+    }
+    shortest_paths[0].len() - 1
+}
+
+/// Returns the given number of reproducible shuffles of King Wen's sequence.
+fn shuffles(num_sequences: usize) -> Vec<Vec<usize>> {
+    (0..num_sequences)
+        .map(|i| {
+            let mut rng = seeded_rng(0);
+            rng.set_stream(i as u64);
+            let mut sequence = king_wen();
+            sequence.shuffle(&mut rng);
+            sequence
+        })
+        .collect()
+}
+
+/// Benchmarks the search for the random sequence with the fewest operations. The same shuffles are
+/// analyzed with the search that cloned paths over the hash map of hexagrams, with the current
+/// search without any cache, and with the shared distance matrix. The search of the whole distance
+/// matrix, which is what the first random sequences analyzed by a process pay for, is measured on
+/// its own.
+fn random_sequences(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_min_random_sequence");
+    group.sample_size(10);
+    let sequences = shuffles(10);
+    let hexagrams: Vec<Hexagram> = (1..=64)
+        .map(Hexagram::from_number)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    group.bench_function("10_sequences/baseline", |b| {
+        b.iter(|| {
+            sequences
+                .iter()
+                .map(|sequence| {
+                    sequence
+                        .windows(2)
+                        .map(|pair| baseline_ops(&hexagrams[pair[0] - 1], &hexagrams[pair[1] - 1]))
+                        .sum::<usize>()
+                })
+                .min()
+        })
+    });
+    group.bench_function("10_sequences/uncached", |b| {
+        b.iter(|| {
+            sequences
+                .iter()
+                .map(|sequence| {
+                    sequence
+                        .windows(2)
+                        .map(|pair| {
+                            let searcher = HexagramSearcher::new(pair[0], pair[1]).unwrap();
+                            searcher.find_shortest_paths(false)[0].len() - 1
+                        })
+                        .sum::<usize>()
+                })
+                .min()
+        })
+    });
+
+    DISTANCE_MATRIX.compute_all().unwrap();
+    group.bench_function("10_sequences/cached", |b| {
+        b.iter(|| find_min_random_sequence(&king_wen(), 10, Some(0)).unwrap())
+    });
+
+    group.bench_function("distance_matrix", |b| {
+        b.iter_batched(
+            DistanceMatrix::new,
+            |matrix| {
+                matrix.compute_all().unwrap();
+                matrix
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, operations, shortest_paths, random_sequences);
+criterion_main!(benches);
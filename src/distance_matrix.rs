@@ -0,0 +1,251 @@
+//! Module containing the matrix of the shortest paths between every pair of hexagrams.
+//!
+//! Every sequence analysis needs the shortest paths between its consecutive hexagrams, and the
+//! analyses of random sequences need the same pairs over and over. The shared
+//! [`DISTANCE_MATRIX`] searches the paths between a pair of hexagrams the first time they are
+//! needed and keeps them for the rest of the process, so that each of the 4096 pairs is searched
+//! at most once.
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use lazy_static::lazy_static;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, sync::OnceLock};
+
+use crate::iching_analyzer::{count_line_changes, HexagramSearcher, Path};
+
+/// The number of hexagrams, and thus of rows and columns of the matrix.
+const NUM_HEXAGRAMS: usize = 64;
+
+/// The shortest paths between every pair of hexagrams, searched on demand and cached. Only the
+/// shortest paths with the fewest line changes are kept, as in a sequence analysis.
+#[derive(Debug)]
+pub struct DistanceMatrix {
+    /// The paths between each pair of hexagrams, indexed by the position of the start hexagram
+    /// times 64 plus the position of the end hexagram.
+    entries: Vec<OnceLock<Vec<Path>>>,
+}
+
+impl Default for DistanceMatrix {
+    fn default() -> Self {
+        Self {
+            entries: (0..NUM_HEXAGRAMS * NUM_HEXAGRAMS)
+                .map(|_| OnceLock::new())
+                .collect(),
+        }
+    }
+}
+
+impl DistanceMatrix {
+    /// Creates an empty matrix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shortest paths with the fewest line changes from the start hexagram to the end
+    /// hexagram, searching them if it is the first time they are needed. The only path from a
+    /// hexagram to itself contains no operations.
+    pub fn shortest_paths(&self, start: usize, end: usize) -> Result<&[Path]> {
+        if !(1..=NUM_HEXAGRAMS).contains(&start) || !(1..=NUM_HEXAGRAMS).contains(&end) {
+            bail!("invalid pair of hexagram numbers: {} and {}", start, end);
+        }
+        let entry = &self.entries[(start - 1) * NUM_HEXAGRAMS + end - 1];
+        if let Some(paths) = entry.get() {
+            return Ok(paths);
+        }
+
+        let paths = HexagramSearcher::new(start, end)?.find_shortest_paths(false);
+        Ok(entry.get_or_init(|| paths))
+    }
+
+    /// Returns the number of operations of the shortest paths between both hexagrams.
+    pub fn ops(&self, start: usize, end: usize) -> Result<u64> {
+        Ok((self.shortest_paths(start, end)?[0].len() - 1) as u64)
+    }
+
+    /// Returns the number of line changes of the shortest paths with the fewest line changes
+    /// between both hexagrams.
+    pub fn line_changes(&self, start: usize, end: usize) -> Result<u64> {
+        Ok(count_line_changes(&self.shortest_paths(start, end)?[0]))
+    }
+
+    /// Returns the number of shortest paths with the fewest line changes between both hexagrams.
+    pub fn num_paths(&self, start: usize, end: usize) -> Result<usize> {
+        Ok(self.shortest_paths(start, end)?.len())
+    }
+
+    /// Searches the paths between all the pairs of hexagrams that have not been searched yet, in
+    /// parallel.
+    pub fn compute_all(&self) -> Result<()> {
+        (0..NUM_HEXAGRAMS * NUM_HEXAGRAMS)
+            .into_par_iter()
+            .try_for_each(|i| {
+                self.shortest_paths(i / NUM_HEXAGRAMS + 1, i % NUM_HEXAGRAMS + 1)
+                    .map(|_| ())
+            })
+    }
+}
+
+lazy_static! {
+    /// The matrix shared by all the analyses of the process.
+    pub static ref DISTANCE_MATRIX: DistanceMatrix = DistanceMatrix::new();
+}
+
+/// A quantity stored in the distance matrix.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DistanceMetric {
+    /// The number of operations of the shortest paths.
+    #[default]
+    Ops,
+
+    /// The number of line changes of the shortest paths with the fewest line changes.
+    LineChanges,
+
+    /// The number of shortest paths with the fewest line changes.
+    Paths,
+}
+
+impl Display for DistanceMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceMetric::Ops => write!(f, "ops"),
+            DistanceMetric::LineChanges => write!(f, "line-changes"),
+            DistanceMetric::Paths => write!(f, "paths"),
+        }
+    }
+}
+
+/// The quantities of the distance matrix for every pair of hexagrams, indexed by the position of
+/// the start hexagram and then by the position of the end hexagram.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DistanceTable {
+    /// The number of operations of the shortest paths.
+    pub ops: Vec<Vec<u64>>,
+
+    /// The number of line changes of the shortest paths with the fewest line changes.
+    pub line_changes: Vec<Vec<u64>>,
+
+    /// The number of shortest paths with the fewest line changes.
+    pub paths: Vec<Vec<usize>>,
+}
+
+impl DistanceTable {
+    /// Builds the table from the given matrix, searching the paths that are still missing.
+    pub fn new(matrix: &DistanceMatrix) -> Result<Self> {
+        matrix.compute_all()?;
+        let mut table = Self {
+            ops: vec![vec![0; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+            line_changes: vec![vec![0; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+            paths: vec![vec![0; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+        };
+        for start in 1..=NUM_HEXAGRAMS {
+            for end in 1..=NUM_HEXAGRAMS {
+                table.ops[start - 1][end - 1] = matrix.ops(start, end)?;
+                table.line_changes[start - 1][end - 1] = matrix.line_changes(start, end)?;
+                table.paths[start - 1][end - 1] = matrix.num_paths(start, end)?;
+            }
+        }
+        Ok(table)
+    }
+
+    /// Returns the value of the metric for the given positions of the start and end hexagrams.
+    fn value(&self, metric: DistanceMetric, start: usize, end: usize) -> u64 {
+        match metric {
+            DistanceMetric::Ops => self.ops[start][end],
+            DistanceMetric::LineChanges => self.line_changes[start][end],
+            DistanceMetric::Paths => self.paths[start][end] as u64,
+        }
+    }
+
+    /// Prints the given metric as a grid, with a row for each start hexagram and a column for each
+    /// end hexagram.
+    pub fn print(&self, metric: DistanceMetric) {
+        let width = (0..NUM_HEXAGRAMS)
+            .flat_map(|start| (0..NUM_HEXAGRAMS).map(move |end| (start, end)))
+            .map(|(start, end)| self.value(metric, start, end).to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(2);
+
+        println!(
+            ">>>>> Distance matrix ({}) from each row to each column",
+            metric
+        );
+        println!();
+        let header: Vec<String> = (1..=NUM_HEXAGRAMS)
+            .map(|end| format!("{:>width$}", end))
+            .collect();
+        println!("{:>2} {}", "", header.join(" "));
+        for start in 0..NUM_HEXAGRAMS {
+            let row: Vec<String> = (0..NUM_HEXAGRAMS)
+                .map(|end| format!("{:>width$}", self.value(metric, start, end)))
+                .collect();
+            println!("{:>2} {}", start + 1, row.join(" "));
+        }
+    }
+
+    /// Returns the table as CSV, with a header and a row for each pair of hexagrams containing the
+    /// start and end hexagrams, the operations, the line changes, and the number of paths.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("start,end,ops,line_changes,paths\n");
+        for start in 0..NUM_HEXAGRAMS {
+            for end in 0..NUM_HEXAGRAMS {
+                csv.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    start + 1,
+                    end + 1,
+                    self.ops[start][end],
+                    self.line_changes[start][end],
+                    self.paths[start][end]
+                ));
+            }
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+
+    /// Verifies that the matrix returns the same paths as a direct search and caches them.
+    #[test]
+    fn test_cached_paths() -> Result<()> {
+        let matrix = DistanceMatrix::new();
+        let expected = HexagramSearcher::new(3, 50)?.find_shortest_paths(false);
+        assert_eq!(matrix.shortest_paths(3, 50)?, expected);
+        assert!(matrix.entries[2 * NUM_HEXAGRAMS + 49].get().is_some());
+        assert!(matrix.entries[49 * NUM_HEXAGRAMS + 2].get().is_none());
+
+        assert_eq!(matrix.ops(1, 2)?, 1);
+        assert_eq!(matrix.line_changes(1, 2)?, 6);
+        assert_eq!(matrix.num_paths(1, 2)?, 1);
+        assert_eq!(matrix.ops(7, 7)?, 0);
+        assert_eq!(matrix.line_changes(7, 7)?, 0);
+        assert!(matrix.shortest_paths(0, 7).is_err());
+        assert!(matrix.shortest_paths(7, 65).is_err());
+        Ok(())
+    }
+
+    /// Verifies that the table is exported as CSV with a row for each pair of hexagrams.
+    #[test]
+    fn test_csv() {
+        let mut table = DistanceTable {
+            ops: vec![vec![1; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+            line_changes: vec![vec![2; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+            paths: vec![vec![3; NUM_HEXAGRAMS]; NUM_HEXAGRAMS],
+        };
+        table.ops[0][1] = 4;
+        let csv = table.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 1 + NUM_HEXAGRAMS * NUM_HEXAGRAMS);
+        assert_eq!(lines[0], "start,end,ops,line_changes,paths");
+        assert_eq!(lines[1], "1,1,1,2,3");
+        assert_eq!(lines[2], "1,2,4,2,3");
+        assert_eq!(lines[4096], "64,64,1,2,3");
+    }
+}
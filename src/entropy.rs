@@ -0,0 +1,285 @@
+//! Module containing the sources of randomness used to cast readings.
+//!
+//! Every casting method only needs to draw small integers in a given range, so a source of
+//! randomness only has to implement [`EntropySource::integer`]. The crate provides sources backed
+//! by random.org, the thread-local generator, a seeded generator, the generator of the operating
+//! system, and any stream of random bytes, such as a hardware random number generator.
+
+use anyhow::{bail, Context, Result};
+use rand::{rngs::ThreadRng, Rng, RngCore};
+use rand_chacha::ChaCha8Rng;
+use std::{fs::File, io::Read, path::Path};
+
+use crate::{
+    iching::seeded_rng,
+    random_org::{RandomOrg, SignedRandom},
+};
+
+/// A source of random integers used to cast readings.
+pub trait EntropySource {
+    /// Draws an integer between `min` and `max`, both inclusive, with uniform probability.
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8>;
+
+    /// Returns the reason why the source fell back to another source of randomness, if it did.
+    fn fallback_reason(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the batches of signed numbers drawn since the last call, if the source signs them.
+    /// Called once the reading has been cast, so that the signatures can be stored on it.
+    fn take_signatures(&mut self) -> Vec<SignedRandom> {
+        Vec::new()
+    }
+}
+
+impl EntropySource for RandomOrg {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        RandomOrg::integer(self, min, max)
+    }
+
+    fn take_signatures(&mut self) -> Vec<SignedRandom> {
+        RandomOrg::take_signatures(self)
+    }
+}
+
+/// Draws an integer between `min` and `max`, both inclusive, from a sequence of random bytes. Bytes
+/// that would bias the result towards the lower values are discarded.
+pub(crate) fn integer_from_bytes(
+    min: u8,
+    max: u8,
+    mut next_byte: impl FnMut() -> Result<u8>,
+) -> Result<u8> {
+    if min > max {
+        bail!("invalid range {}..={}", min, max);
+    }
+    let range = max as u16 - min as u16 + 1;
+    let limit = 256 - 256 % range;
+    loop {
+        let byte = next_byte()? as u16;
+        if byte < limit {
+            return Ok(min + (byte % range) as u8);
+        }
+    }
+}
+
+/// A source that uses the thread-local pseudo-random number generator.
+#[derive(Clone, Debug, Default)]
+pub struct ThreadRandom {
+    /// The generator of the current thread.
+    rng: ThreadRng,
+}
+
+impl ThreadRandom {
+    /// Creates a source that uses the generator of the current thread.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EntropySource for ThreadRandom {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        if min > max {
+            bail!("invalid range {}..={}", min, max);
+        }
+        Ok(self.rng.gen_range(min..=max))
+    }
+}
+
+/// A source that uses a pseudo-random number generator initialized with a seed. The same seed
+/// always produces the same integers, on every platform.
+#[derive(Clone, Debug)]
+pub struct SeededRandom {
+    /// The seeded generator.
+    rng: ChaCha8Rng,
+}
+
+impl SeededRandom {
+    /// Creates a source whose generator is initialized with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: seeded_rng(seed),
+        }
+    }
+}
+
+impl EntropySource for SeededRandom {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        if min > max {
+            bail!("invalid range {}..={}", min, max);
+        }
+        Ok(self.rng.gen_range(min..=max))
+    }
+}
+
+/// A source that uses the random number generator of the operating system, such as
+/// `/dev/urandom` on Linux.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsRandom;
+
+impl EntropySource for OsRandom {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        integer_from_bytes(min, max, || {
+            let mut byte = [0; 1];
+            rand::rngs::OsRng
+                .try_fill_bytes(&mut byte)
+                .context("cannot read from the random number generator of the system")?;
+            Ok(byte[0])
+        })
+    }
+}
+
+/// A source that reads random bytes from a stream, such as a file, a pipe, or a socket connected to
+/// a hardware random number generator. Bytes are read one at a time, so no entropy is wasted on
+/// buffering.
+#[derive(Debug)]
+pub struct ByteStream<R: Read> {
+    /// The stream from which the bytes are read.
+    reader: R,
+}
+
+impl<R: Read> ByteStream<R> {
+    /// Creates a source that reads random bytes from the given stream.
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+}
+
+impl ByteStream<File> {
+    /// Creates a source that reads random bytes from the file at the given path, such as
+    /// `/dev/hwrng` or a named pipe written to by a daemon.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("cannot open entropy source {}", path.display()))?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<R: Read> EntropySource for ByteStream<R> {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        integer_from_bytes(min, max, || {
+            let mut byte = [0; 1];
+            self.reader
+                .read_exact(&mut byte)
+                .context("cannot read from the entropy stream")?;
+            Ok(byte[0])
+        })
+    }
+}
+
+/// A source that draws integers from a primary source until it fails, such as when random.org is
+/// unreachable or its quota is exhausted, and from a secondary source afterwards.
+pub struct Fallback {
+    /// The source used until it fails.
+    primary: Box<dyn EntropySource>,
+
+    /// The source used once the primary source has failed.
+    secondary: Box<dyn EntropySource>,
+
+    /// The error returned by the primary source, if it failed.
+    reason: Option<String>,
+}
+
+impl Fallback {
+    /// Creates a source that falls back to the secondary source when the primary source fails.
+    pub fn new(primary: Box<dyn EntropySource>, secondary: Box<dyn EntropySource>) -> Self {
+        Self {
+            primary,
+            secondary,
+            reason: None,
+        }
+    }
+}
+
+impl EntropySource for Fallback {
+    fn integer(&mut self, min: u8, max: u8) -> Result<u8> {
+        if self.reason.is_none() {
+            match self.primary.integer(min, max) {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    // Only keep the outermost and innermost errors to keep the reason short.
+                    let (outer, root) = (error.to_string(), error.root_cause().to_string());
+                    self.reason = Some(if outer == root {
+                        outer
+                    } else {
+                        format!("{}: {}", outer, root)
+                    });
+                }
+            }
+        }
+        self.secondary.integer(min, max)
+    }
+
+    fn fallback_reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    fn take_signatures(&mut self) -> Vec<SignedRandom> {
+        self.primary.take_signatures()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+
+    /// Verifies that every source only draws integers in the requested range.
+    #[test]
+    fn test_integers_in_range() -> Result<()> {
+        let bytes: Vec<u8> = (0..=255).cycle().take(2000).collect();
+        let mut sources: Vec<Box<dyn EntropySource>> = vec![
+            Box::new(ThreadRandom::new()),
+            Box::new(SeededRandom::new(7)),
+            Box::new(OsRandom),
+            Box::new(ByteStream::new(bytes.as_slice())),
+        ];
+        for source in sources.iter_mut() {
+            for _ in 0..100 {
+                assert!((2..=3).contains(&source.integer(2, 3)?));
+                assert!((1..=47).contains(&source.integer(1, 47)?));
+            }
+            assert!(source.integer(3, 2).is_err());
+        }
+        Ok(())
+    }
+
+    /// Verifies that bytes that would bias the result are discarded and that running out of bytes
+    /// is reported as an error.
+    #[test]
+    fn test_byte_stream() -> Result<()> {
+        // With a range of 47 values, bytes from 235 onwards are discarded.
+        let bytes = [0, 46, 47, 235, 255, 234];
+        let mut source = ByteStream::new(&bytes[..]);
+        assert_eq!(source.integer(1, 47)?, 1);
+        assert_eq!(source.integer(1, 47)?, 47);
+        assert_eq!(source.integer(1, 47)?, 1);
+        assert_eq!(source.integer(1, 47)?, 47);
+        assert!(source.integer(1, 47).is_err());
+        Ok(())
+    }
+
+    /// Verifies that the fallback source switches to the secondary source once the primary source
+    /// fails, and records why.
+    #[test]
+    fn test_fallback() -> Result<()> {
+        let bytes: &'static [u8] = &[0, 1];
+        let mut source = Fallback::new(
+            Box::new(ByteStream::new(bytes)),
+            Box::new(SeededRandom::new(1)),
+        );
+        assert_eq!(source.integer(2, 3)?, 2);
+        assert_eq!(source.integer(2, 3)?, 3);
+        assert!(source.fallback_reason().is_none());
+        for _ in 0..10 {
+            assert!((2..=3).contains(&source.integer(2, 3)?));
+        }
+        let reason = source.fallback_reason().unwrap();
+        assert!(
+            reason.contains("cannot read from the entropy stream"),
+            "{}",
+            reason
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+//! Module containing a compact representation of hexagrams for fast searches.
+//!
+//! A [`Hexagram`] carries its lines, names, and symbol, and its transformations look the resulting
+//! lines up in a hash map. The searches over the 64 hexagrams apply millions of transformations,
+//! so they work instead with [`HexagramBits`], which stores the six lines in the low bits of a
+//! byte and implements every transformation with a few bit operations. The number of the hexagram
+//! in King Wen's sequence is read from a constant table indexed by those bits.
+
+use anyhow::{bail, Result};
+
+use crate::iching::{create_hexagram, Hexagram, Line, HEXAGRAMS};
+
+/// The bits of the lines of each hexagram, in the order of King Wen's sequence. Bit `i` holds
+/// line `i` counting from the bottom, set for a closed line, so each literal reads as the hexagram
+/// lying on its right side, with the top line first.
+pub const HEXAGRAM_BITS: [u8; 64] = [
+    0b111111, 0b000000, 0b010001, 0b100010, 0b010111, 0b111010, 0b000010, 0b010000, 0b110111,
+    0b111011, 0b000111, 0b111000, 0b111101, 0b101111, 0b000100, 0b001000, 0b011001, 0b100110,
+    0b000011, 0b110000, 0b101001, 0b100101, 0b100000, 0b000001, 0b111001, 0b100111, 0b100001,
+    0b011110, 0b010010, 0b101101, 0b011100, 0b001110, 0b111100, 0b001111, 0b101000, 0b000101,
+    0b110101, 0b101011, 0b010100, 0b001010, 0b100011, 0b110001, 0b011111, 0b111110, 0b011000,
+    0b000110, 0b011010, 0b010110, 0b011101, 0b101110, 0b001001, 0b100100, 0b110100, 0b001011,
+    0b001101, 0b101100, 0b110110, 0b011011, 0b110010, 0b010011, 0b110011, 0b001100, 0b010101,
+    0b101010,
+];
+
+/// The number in King Wen's sequence of the hexagram with each combination of lines, indexed by
+/// the bits of the lines.
+pub const HEXAGRAM_NUMBERS: [u8; 64] = hexagram_numbers();
+
+/// Builds the table of numbers by inverting the table of bits.
+const fn hexagram_numbers() -> [u8; 64] {
+    let mut numbers = [0; 64];
+    let mut i = 0;
+    while i < 64 {
+        numbers[HEXAGRAM_BITS[i] as usize] = i as u8 + 1;
+        i += 1;
+    }
+    numbers
+}
+
+/// The bits of the lines of the bottom trigram.
+const BOTTOM_TRIGRAM: u8 = 0b000111;
+
+/// The bits of the lines of the top trigram.
+const TOP_TRIGRAM: u8 = 0b111000;
+
+/// The bits of all the lines.
+const ALL_LINES: u8 = BOTTOM_TRIGRAM | TOP_TRIGRAM;
+
+/// Reverses the order of the three lines of a trigram.
+const fn reverse_trigram(trigram: u8) -> u8 {
+    ((trigram & 0b001) << 2) | (trigram & 0b010) | ((trigram & 0b100) >> 2)
+}
+
+/// Spreads the three lines of a trigram to every other bit, leaving room to interleave the lines
+/// of another trigram.
+const fn spread_trigram(trigram: u8) -> u8 {
+    (trigram & 0b001) | ((trigram & 0b010) << 1) | ((trigram & 0b100) << 2)
+}
+
+/// A hexagram stored as the bits of its six lines. Bit `i` holds line `i` counting from the
+/// bottom, and it is set for a closed line.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexagramBits(u8);
+
+impl HexagramBits {
+    /// Creates a hexagram from the bits of its lines. Only the six lowest bits are used.
+    pub const fn new(bits: u8) -> Self {
+        Self(bits & ALL_LINES)
+    }
+
+    /// Returns the hexagram with the given number in King Wen's sequence, from 1 to 64.
+    pub fn from_number(number: u8) -> Result<Self> {
+        if !(1..=64).contains(&number) {
+            bail!("invalid hexagram number: {}", number);
+        }
+        Ok(Self(HEXAGRAM_BITS[number as usize - 1]))
+    }
+
+    /// Creates a hexagram from its lines, from the bottom one to the top one.
+    pub fn from_lines(lines: &[Line; 6]) -> Self {
+        Self(
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| ((*line == Line::Closed) as u8) << i)
+                .sum(),
+        )
+    }
+
+    /// Returns the bits of the lines of the hexagram.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the number of the hexagram in King Wen's sequence, from 1 to 64.
+    pub const fn number(self) -> u8 {
+        HEXAGRAM_NUMBERS[self.0 as usize]
+    }
+
+    /// Returns the lines of the hexagram, from the bottom one to the top one.
+    pub fn lines(self) -> [Line; 6] {
+        std::array::from_fn(|i| match (self.0 >> i) & 1 {
+            0 => Line::Open,
+            _ => Line::Closed,
+        })
+    }
+
+    /// Returns the full hexagram, with its names and symbol.
+    pub fn hexagram(self) -> Hexagram {
+        let (number, lines, ..) = HEXAGRAMS[self.number() as usize - 1];
+        create_hexagram(number, lines)
+    }
+
+    /// Returns the bottom trigram of the hexagram, as the bits of its lines.
+    const fn bottom(self) -> u8 {
+        self.0 & BOTTOM_TRIGRAM
+    }
+
+    /// Returns the top trigram of the hexagram, as the bits of its lines.
+    const fn top(self) -> u8 {
+        self.0 >> 3
+    }
+
+    /// Returns the hexagram formed by the given bottom and top trigrams.
+    const fn from_trigrams(bottom: u8, top: u8) -> Self {
+        Self(bottom | (top << 3))
+    }
+
+    /// Counts the number of line changes between this and other hexagram.
+    pub const fn num_line_changes(self, other: Self) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    /// Returns the hexagram obtained by inverting all lines in this hexagram.
+    pub const fn inverse(self) -> Self {
+        Self(self.0 ^ ALL_LINES)
+    }
+
+    /// Returns the hexagram obtained by inverting the bottom trigram of this hexagram.
+    pub const fn inverse_bottom_trigram(self) -> Self {
+        Self(self.0 ^ BOTTOM_TRIGRAM)
+    }
+
+    /// Returns the hexagram obtained by inverting the top trigram of this hexagram.
+    pub const fn inverse_top_trigram(self) -> Self {
+        Self(self.0 ^ TOP_TRIGRAM)
+    }
+
+    /// Returns the hexagram obtained by inverting the line with the given zero-based index, with
+    /// zero being the bottom line.
+    pub const fn inverse_line(self, index: usize) -> Self {
+        Self::new(self.0 ^ (1 << index))
+    }
+
+    /// Returns the hexagram obtained by reversing the order of the lines in this hexagram.
+    pub const fn reverse(self) -> Self {
+        Self::from_trigrams(reverse_trigram(self.top()), reverse_trigram(self.bottom()))
+    }
+
+    /// Returns the hexagram obtained by reversing the bottom trigram of this hexagram.
+    pub const fn reverse_bottom_trigram(self) -> Self {
+        Self::from_trigrams(reverse_trigram(self.bottom()), self.top())
+    }
+
+    /// Returns the hexagram obtained by reversing the top trigram of this hexagram.
+    pub const fn reverse_top_trigram(self) -> Self {
+        Self::from_trigrams(self.bottom(), reverse_trigram(self.top()))
+    }
+
+    /// Returns the hexagram obtained by flipping the top and bottom trigrams of this hexagram.
+    pub const fn flip_trigrams(self) -> Self {
+        Self::from_trigrams(self.top(), self.bottom())
+    }
+
+    /// Returns the hexagram obtained by mirroring the trigrams of this hexagram along the dividing
+    /// line between the two trigrams.
+    pub const fn mirror_trigrams(self) -> Self {
+        Self::from_trigrams(reverse_trigram(self.bottom()), reverse_trigram(self.top()))
+    }
+
+    /// Returns the hexagram obtained by superimposing the upper and lower nuclear trigrams of
+    /// this hexagram.
+    pub const fn use_nuclear_trigrams(self) -> Self {
+        Self::from_trigrams(
+            (self.0 >> 1) & BOTTOM_TRIGRAM,
+            (self.0 >> 2) & BOTTOM_TRIGRAM,
+        )
+    }
+
+    /// Returns the hexagram obtained by mixing the bottom and top trigrams of this hexagram,
+    /// with the first line of the bottom trigram being the first line of the result.
+    pub const fn mix_trigrams_bottom_first(self) -> Self {
+        Self(spread_trigram(self.bottom()) | (spread_trigram(self.top()) << 1))
+    }
+
+    /// Returns the hexagram obtained by mixing the bottom and top trigrams of this hexagram,
+    /// with the first line of the top trigram being the first line of the result.
+    pub const fn mix_trigrams_top_first(self) -> Self {
+        Self(spread_trigram(self.top()) | (spread_trigram(self.bottom()) << 1))
+    }
+}
+
+impl From<&Hexagram> for HexagramBits {
+    fn from(hexagram: &Hexagram) -> Self {
+        Self::from_lines(&hexagram.lines)
+    }
+}
+
+impl From<HexagramBits> for Hexagram {
+    fn from(bits: HexagramBits) -> Self {
+        bits.hexagram()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::iching::HexagramLine;
+
+    /// Returns all the hexagrams, in the order of King Wen's sequence.
+    fn hexagrams() -> Result<Vec<Hexagram>> {
+        (1..=64).map(Hexagram::from_number).collect()
+    }
+
+    /// Verifies that the tables of bits and numbers match the table of hexagrams.
+    #[test]
+    fn test_tables() -> Result<()> {
+        for hexagram in hexagrams()? {
+            let bits = HexagramBits::from(&hexagram);
+            assert_eq!(bits, HexagramBits::from_lines(&hexagram.lines));
+            assert_eq!(bits, HexagramBits::from_number(hexagram.number)?);
+            assert_eq!(bits.number(), hexagram.number);
+            assert_eq!(bits.lines(), hexagram.lines);
+            assert_eq!(bits.hexagram(), hexagram);
+        }
+        assert!(HexagramBits::from_number(0).is_err());
+        assert!(HexagramBits::from_number(65).is_err());
+        assert_eq!(HexagramBits::new(0xff), HexagramBits::new(0b111111));
+
+        // The bits come from the lines, whatever the number of the hexagram says.
+        let hexagram = Hexagram {
+            number: 0,
+            ..Hexagram::from_number(63)?
+        };
+        assert_eq!(HexagramBits::from(&hexagram).number(), 63);
+        Ok(())
+    }
+
+    /// Verifies that every transformation of the bits gives the same hexagram as the
+    /// transformation of the lines.
+    #[test]
+    fn test_transformations() -> Result<()> {
+        let lines = [
+            HexagramLine::First,
+            HexagramLine::Second,
+            HexagramLine::Third,
+            HexagramLine::Fourth,
+            HexagramLine::Fifth,
+            HexagramLine::Sixth,
+        ];
+        for hexagram in hexagrams()? {
+            let bits = HexagramBits::from(&hexagram);
+            let pairs = [
+                (bits.inverse(), hexagram.inverse()),
+                (
+                    bits.inverse_bottom_trigram(),
+                    hexagram.inverse_bottom_trigram(),
+                ),
+                (bits.inverse_top_trigram(), hexagram.inverse_top_trigram()),
+                (bits.reverse(), hexagram.reverse()),
+                (
+                    bits.reverse_bottom_trigram(),
+                    hexagram.reverse_bottom_trigram(),
+                ),
+                (bits.reverse_top_trigram(), hexagram.reverse_top_trigram()),
+                (bits.flip_trigrams(), hexagram.flip_trigrams()),
+                (bits.mirror_trigrams(), hexagram.mirror_trigrams()),
+                (bits.use_nuclear_trigrams(), hexagram.use_nuclear_trigrams()),
+                (
+                    bits.mix_trigrams_bottom_first(),
+                    hexagram.mix_trigrams_bottom_first(),
+                ),
+                (
+                    bits.mix_trigrams_top_first(),
+                    hexagram.mix_trigrams_top_first(),
+                ),
+            ];
+            for (i, (from_bits, expected)) in pairs.into_iter().enumerate() {
+                assert_eq!(from_bits.hexagram(), expected, "{} #{}", hexagram.number, i);
+            }
+            for (i, line) in lines.iter().enumerate() {
+                assert_eq!(
+                    bits.inverse_line(i).hexagram(),
+                    hexagram.inverse_line(*line)
+                );
+            }
+            for other in hexagrams()? {
+                assert_eq!(
+                    bits.num_line_changes(HexagramBits::from(&other)) as usize,
+                    hexagram.num_line_changes(&other)
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,14 +3,25 @@
 use anyhow::{anyhow, bail, Result};
 use clap::ValueEnum;
 use lazy_static::lazy_static;
-use rand::Rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize, Serializer};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
+    str::FromStr,
+};
+
+use crate::{
+    entropy::EntropySource,
+    plum_blossom::{branch_name, LunarTime},
+    random_org::SignedRandom,
+    text_pack::{TextPack, BUILTIN_TEXT_PACK},
 };
 
 /// The type of line in a hexagram.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Line {
     /// An open line, representing yin energy.
     Open,
@@ -49,7 +60,7 @@ pub enum TrigramLine {
 
 impl TrigramLine {
     /// Converts a line into an array index.
-    fn line_to_index(&self) -> usize {
+    pub(crate) fn line_to_index(&self) -> usize {
         match self {
             TrigramLine::First => 0,
             TrigramLine::Second => 1,
@@ -59,7 +70,7 @@ impl TrigramLine {
 }
 
 /// A single trigram, consisting of three lines.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct Trigram {
     /// The number of the trigram, from 1 to 8.
     pub number: u8,
@@ -67,12 +78,47 @@ pub struct Trigram {
     /// The lines of the hexagram. The first line is the bottom line, and the last line is the top
     /// one.
     pub lines: [Line; 3],
+
+    /// The name of the trigram in pinyin.
+    pub name: &'static str,
+
+    /// The name of the trigram in Chinese characters.
+    pub chinese_name: &'static str,
+
+    /// Common English translations of the name of the trigram.
+    pub english_names: &'static [&'static str],
+
+    /// The Unicode symbol of the trigram, in the range U+2630 to U+2637.
+    pub symbol: char,
+}
+
+impl Display for Trigram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} ({})",
+            self.number,
+            self.symbol,
+            self.name,
+            self.chinese_name,
+            self.english_names.join(" / ")
+        )
+    }
 }
 
 impl Trigram {
+    /// Returns the trigram with the given number in the table of trigrams, from 1 (Qian) to 8
+    /// (Dui), in the order Qian, Zhen, Kan, Gen, Kun, Xun, Li, and Dui.
+    pub fn from_number(number: u8) -> Result<Self> {
+        if !(1..=8).contains(&number) {
+            bail!("invalid trigram number: {}", number);
+        }
+        Ok(create_trigram(&TRIGRAMS[number as usize - 1]))
+    }
+
     /// Prints the trigram to the console.
     pub fn print(&self) {
-        println!("     {}\n", self.number);
+        println!("     {}\n", self);
         for line in self.lines.iter().rev() {
             match line {
                 Line::Open => print!("----    ----"),
@@ -106,32 +152,76 @@ impl Trigram {
     }
 }
 
+/// An entry in the table of trigrams, containing the number, lines, pinyin name, Chinese name,
+/// English names, and Unicode symbol of the trigram.
+type TrigramEntry = (
+    u8,
+    [u8; 3],
+    &'static str,
+    &'static str,
+    &'static [&'static str],
+    char,
+);
+
 /// The list of all I Ching trigrams.
-static TRIGRAMS: [(u8, [u8; 3]); 8] = [
-    (1, [1, 1, 1]),
-    (2, [1, 0, 0]),
-    (3, [0, 1, 0]),
-    (4, [0, 0, 1]),
-    (5, [0, 0, 0]),
-    (6, [0, 1, 1]),
-    (7, [1, 0, 1]),
-    (8, [1, 1, 0]),
+static TRIGRAMS: [TrigramEntry; 8] = [
+    (1, [1, 1, 1], "Qian", "乾", &["The Creative", "Heaven"], '☰'),
+    (
+        2,
+        [1, 0, 0],
+        "Zhen",
+        "震",
+        &["The Arousing", "Thunder"],
+        '☳',
+    ),
+    (3, [0, 1, 0], "Kan", "坎", &["The Abysmal", "Water"], '☵'),
+    (
+        4,
+        [0, 0, 1],
+        "Gen",
+        "艮",
+        &["Keeping Still", "Mountain"],
+        '☶',
+    ),
+    (5, [0, 0, 0], "Kun", "坤", &["The Receptive", "Earth"], '☷'),
+    (6, [0, 1, 1], "Xun", "巽", &["The Gentle", "Wind"], '☴'),
+    (7, [1, 0, 1], "Li", "離", &["The Clinging", "Fire"], '☲'),
+    (8, [1, 1, 0], "Dui", "兌", &["The Joyous", "Lake"], '☱'),
 ];
 
-/// Creates a trigram from a number and a list of lines.
-fn create_trigram(number: u8, lines: [u8; 3]) -> Trigram {
+/// The number in the table of trigrams of each trigram in the Early Heaven (Fu Xi) order used by
+/// the dice and plum blossom methods: Qian, Dui, Li, Zhen, Xun, Kan, Gen, and Kun.
+const EARLY_HEAVEN_TRIGRAMS: [u8; 8] = [1, 8, 7, 2, 6, 3, 4, 5];
+
+/// Returns the lines of the trigram with the given Early Heaven number, from 1 to 8, from bottom to
+/// top. Each line is 1 for a closed line and 0 for an open one.
+fn early_heaven_trigram(number: u8) -> Result<[u8; 3]> {
+    if !(1..=8).contains(&number) {
+        bail!("invalid Early Heaven trigram number: {}", number);
+    }
+    let table_number = EARLY_HEAVEN_TRIGRAMS[number as usize - 1];
+    Ok(TRIGRAMS[table_number as usize - 1].1)
+}
+
+/// Creates a trigram from an entry in the table of trigrams.
+fn create_trigram(entry: &TrigramEntry) -> Trigram {
+    let (number, lines, name, chinese_name, english_names, symbol) = *entry;
     Trigram {
         number,
         lines: [lines[0].into(), lines[1].into(), lines[2].into()],
+        name,
+        chinese_name,
+        english_names,
+        symbol,
     }
 }
 
 /// Generates a map of lines to trigram number for fast lookup.
 fn trigram_index() -> HashMap<[Line; 3], Trigram> {
     let mut index = HashMap::new();
-    for (number, lines) in TRIGRAMS.iter() {
-        let hex = create_trigram(*number, *lines);
-        index.insert(hex.lines, hex);
+    for entry in TRIGRAMS.iter() {
+        let trigram = create_trigram(entry);
+        index.insert(trigram.lines, trigram);
     }
     index
 }
@@ -142,7 +232,8 @@ lazy_static! {
 }
 
 /// The position of a line in a hexagram.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum HexagramLine {
     First,
@@ -155,7 +246,7 @@ pub enum HexagramLine {
 
 impl HexagramLine {
     /// Converts a line into an array index.
-    fn line_to_index(&self) -> usize {
+    pub(crate) fn line_to_index(&self) -> usize {
         match self {
             HexagramLine::First => 0,
             HexagramLine::Second => 1,
@@ -168,7 +259,7 @@ impl HexagramLine {
 }
 
 /// A single hexagram in a reading, consisting of six lines.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct Hexagram {
     /// The number of the hexagram, from 1 to 64.
     pub number: u8,
@@ -176,19 +267,67 @@ pub struct Hexagram {
     /// The lines of the hexagram. The first line is the bottom line, and the last line is the top
     /// one.
     pub lines: [Line; 6],
+
+    /// The name of the hexagram in pinyin.
+    pub name: &'static str,
+
+    /// The name of the hexagram in Chinese characters.
+    pub chinese_name: &'static str,
+
+    /// Common English translations of the name of the hexagram.
+    pub english_names: &'static [&'static str],
+
+    /// The Unicode symbol of the hexagram, in the range U+4DC0 to U+4DFF.
+    pub symbol: char,
+}
+
+impl Display for Hexagram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} ({})",
+            self.number,
+            self.symbol,
+            self.name,
+            self.chinese_name,
+            self.english_names.join(" / ")
+        )
+    }
 }
 
 impl Hexagram {
+    /// Returns the hexagram with the given number in King Wen's sequence, from 1 to 64.
+    pub fn from_number(number: u8) -> Result<Self> {
+        if !(1..=64).contains(&number) {
+            bail!("invalid hexagram number: {}", number);
+        }
+        let (number, lines, ..) = HEXAGRAMS[number as usize - 1];
+        Ok(create_hexagram(number, lines))
+    }
+
+    /// Returns the hexagram formed by the given lower and upper trigrams.
+    pub fn from_trigrams(lower: &Trigram, upper: &Trigram) -> Self {
+        let lines = [
+            lower.lines[0],
+            lower.lines[1],
+            lower.lines[2],
+            upper.lines[0],
+            upper.lines[1],
+            upper.lines[2],
+        ];
+        HEXAGRAM_INDEX[&lines]
+    }
+
     /// Prints the hexagram to the console.
     pub fn print(&self, changing_lines: Option<&HashSet<usize>>) {
-        println!("     {}\n", self.number);
+        println!("     {}\n", self);
         for (i, line) in self.lines.iter().enumerate().rev() {
             match line {
                 Line::Open => print!("----    ----"),
                 Line::Closed => print!("------------"),
             }
 
-            if changing_lines.map_or(false, |m| m.contains(&i)) {
+            if changing_lines.is_some_and(|m| m.contains(&i)) {
                 print!("  *")
             }
             println!()
@@ -198,18 +337,10 @@ impl Hexagram {
     /// Returns the bottom and top trigrams of the hexagram.
     pub fn trigrams(&self) -> (Trigram, Trigram) {
         let lines = [self.lines[0], self.lines[1], self.lines[2]];
-        let number = TRIGRAM_INDEX
-            .get(&lines)
-            .map(|trigram| trigram.number)
-            .unwrap();
-        let bottom = Trigram { number, lines };
+        let bottom = TRIGRAM_INDEX.get(&lines).copied().unwrap();
 
         let lines = [self.lines[3], self.lines[4], self.lines[5]];
-        let number = TRIGRAM_INDEX
-            .get(&lines)
-            .map(|trigram| trigram.number)
-            .unwrap();
-        let top = Trigram { number, lines };
+        let top = TRIGRAM_INDEX.get(&lines).copied().unwrap();
 
         (bottom, top)
     }
@@ -218,18 +349,10 @@ impl Hexagram {
     /// trigrams formed by the inner lines of the hexagram.
     pub fn nuclear_trigrams(&self) -> (Trigram, Trigram) {
         let lines = [self.lines[1], self.lines[2], self.lines[3]];
-        let number = TRIGRAM_INDEX
-            .get(&lines)
-            .map(|trigram| trigram.number)
-            .unwrap();
-        let bottom = Trigram { number, lines };
+        let bottom = TRIGRAM_INDEX.get(&lines).copied().unwrap();
 
         let lines = [self.lines[2], self.lines[3], self.lines[4]];
-        let number = TRIGRAM_INDEX
-            .get(&lines)
-            .map(|trigram| trigram.number)
-            .unwrap();
-        let top = Trigram { number, lines };
+        let top = TRIGRAM_INDEX.get(&lines).copied().unwrap();
 
         (bottom, top)
     }
@@ -408,84 +531,549 @@ impl Hexagram {
     }
 }
 
-/// The list of all I Ching hexagrams.
-pub static HEXAGRAMS: [(u8, [u8; 6]); 64] = [
-    (1, [1, 1, 1, 1, 1, 1]),
-    (2, [0, 0, 0, 0, 0, 0]),
-    (3, [1, 0, 0, 0, 1, 0]),
-    (4, [0, 1, 0, 0, 0, 1]),
-    (5, [1, 1, 1, 0, 1, 0]),
-    (6, [0, 1, 0, 1, 1, 1]),
-    (7, [0, 1, 0, 0, 0, 0]),
-    (8, [0, 0, 0, 0, 1, 0]),
-    (9, [1, 1, 1, 0, 1, 1]),
-    (10, [1, 1, 0, 1, 1, 1]),
-    (11, [1, 1, 1, 0, 0, 0]),
-    (12, [0, 0, 0, 1, 1, 1]),
-    (13, [1, 0, 1, 1, 1, 1]),
-    (14, [1, 1, 1, 1, 0, 1]),
-    (15, [0, 0, 1, 0, 0, 0]),
-    (16, [0, 0, 0, 1, 0, 0]),
-    (17, [1, 0, 0, 1, 1, 0]),
-    (18, [0, 1, 1, 0, 0, 1]),
-    (19, [1, 1, 0, 0, 0, 0]),
-    (20, [0, 0, 0, 0, 1, 1]),
-    (21, [1, 0, 0, 1, 0, 1]),
-    (22, [1, 0, 1, 0, 0, 1]),
-    (23, [0, 0, 0, 0, 0, 1]),
-    (24, [1, 0, 0, 0, 0, 0]),
-    (25, [1, 0, 0, 1, 1, 1]),
-    (26, [1, 1, 1, 0, 0, 1]),
-    (27, [1, 0, 0, 0, 0, 1]),
-    (28, [0, 1, 1, 1, 1, 0]),
-    (29, [0, 1, 0, 0, 1, 0]),
-    (30, [1, 0, 1, 1, 0, 1]),
-    (31, [0, 0, 1, 1, 1, 0]),
-    (32, [0, 1, 1, 1, 0, 0]),
-    (33, [0, 0, 1, 1, 1, 1]),
-    (34, [1, 1, 1, 1, 0, 0]),
-    (35, [0, 0, 0, 1, 0, 1]),
-    (36, [1, 0, 1, 0, 0, 0]),
-    (37, [1, 0, 1, 0, 1, 1]),
-    (38, [1, 1, 0, 1, 0, 1]),
-    (39, [0, 0, 1, 0, 1, 0]),
-    (40, [0, 1, 0, 1, 0, 0]),
-    (41, [1, 1, 0, 0, 0, 1]),
-    (42, [1, 0, 0, 0, 1, 1]),
-    (43, [1, 1, 1, 1, 1, 0]),
-    (44, [0, 1, 1, 1, 1, 1]),
-    (45, [0, 0, 0, 1, 1, 0]),
-    (46, [0, 1, 1, 0, 0, 0]),
-    (47, [0, 1, 0, 1, 1, 0]),
-    (48, [0, 1, 1, 0, 1, 0]),
-    (49, [1, 0, 1, 1, 1, 0]),
-    (50, [0, 1, 1, 1, 0, 1]),
-    (51, [1, 0, 0, 1, 0, 0]),
-    (52, [0, 0, 1, 0, 0, 1]),
-    (53, [0, 0, 1, 0, 1, 1]),
-    (54, [1, 1, 0, 1, 0, 0]),
-    (55, [1, 0, 1, 1, 0, 0]),
-    (56, [0, 0, 1, 1, 0, 1]),
-    (57, [0, 1, 1, 0, 1, 1]),
-    (58, [1, 1, 0, 1, 1, 0]),
-    (59, [0, 1, 0, 0, 1, 1]),
-    (60, [1, 1, 0, 0, 1, 0]),
-    (61, [1, 1, 0, 0, 1, 1]),
-    (62, [0, 0, 1, 1, 0, 0]),
-    (63, [1, 0, 1, 0, 1, 0]),
-    (64, [0, 1, 0, 1, 0, 1]),
+/// An entry in the table of hexagrams, containing the number, lines, pinyin name, Chinese name,
+/// English names, and Unicode symbol of the hexagram.
+pub type HexagramEntry = (
+    u8,
+    [u8; 6],
+    &'static str,
+    &'static str,
+    &'static [&'static str],
+    char,
+);
+
+/// The list of all I Ching hexagrams, in the order of King Wen's sequence.
+pub static HEXAGRAMS: [HexagramEntry; 64] = [
+    (
+        1,
+        [1, 1, 1, 1, 1, 1],
+        "Qian",
+        "乾",
+        &["The Creative", "Force"],
+        '䷀',
+    ),
+    (
+        2,
+        [0, 0, 0, 0, 0, 0],
+        "Kun",
+        "坤",
+        &["The Receptive", "Field"],
+        '䷁',
+    ),
+    (
+        3,
+        [1, 0, 0, 0, 1, 0],
+        "Zhun",
+        "屯",
+        &["Difficulty at the Beginning", "Sprouting"],
+        '䷂',
+    ),
+    (
+        4,
+        [0, 1, 0, 0, 0, 1],
+        "Meng",
+        "蒙",
+        &["Youthful Folly", "Enveloping"],
+        '䷃',
+    ),
+    (
+        5,
+        [1, 1, 1, 0, 1, 0],
+        "Xu",
+        "需",
+        &["Waiting", "Attending"],
+        '䷄',
+    ),
+    (
+        6,
+        [0, 1, 0, 1, 1, 1],
+        "Song",
+        "訟",
+        &["Conflict", "Arguing"],
+        '䷅',
+    ),
+    (
+        7,
+        [0, 1, 0, 0, 0, 0],
+        "Shi",
+        "師",
+        &["The Army", "Leading"],
+        '䷆',
+    ),
+    (
+        8,
+        [0, 0, 0, 0, 1, 0],
+        "Bi",
+        "比",
+        &["Holding Together", "Grouping"],
+        '䷇',
+    ),
+    (
+        9,
+        [1, 1, 1, 0, 1, 1],
+        "Xiao Chu",
+        "小畜",
+        &["The Taming Power of the Small", "Small Accumulating"],
+        '䷈',
+    ),
+    (
+        10,
+        [1, 1, 0, 1, 1, 1],
+        "Lü",
+        "履",
+        &["Treading", "Conduct"],
+        '䷉',
+    ),
+    (
+        11,
+        [1, 1, 1, 0, 0, 0],
+        "Tai",
+        "泰",
+        &["Peace", "Pervading"],
+        '䷊',
+    ),
+    (
+        12,
+        [0, 0, 0, 1, 1, 1],
+        "Pi",
+        "否",
+        &["Standstill", "Obstruction"],
+        '䷋',
+    ),
+    (
+        13,
+        [1, 0, 1, 1, 1, 1],
+        "Tong Ren",
+        "同人",
+        &["Fellowship with Men", "Concording People"],
+        '䷌',
+    ),
+    (
+        14,
+        [1, 1, 1, 1, 0, 1],
+        "Da You",
+        "大有",
+        &["Possession in Great Measure", "Great Possessing"],
+        '䷍',
+    ),
+    (
+        15,
+        [0, 0, 1, 0, 0, 0],
+        "Qian",
+        "謙",
+        &["Modesty", "Humbling"],
+        '䷎',
+    ),
+    (
+        16,
+        [0, 0, 0, 1, 0, 0],
+        "Yu",
+        "豫",
+        &["Enthusiasm", "Providing-For"],
+        '䷏',
+    ),
+    (17, [1, 0, 0, 1, 1, 0], "Sui", "隨", &["Following"], '䷐'),
+    (
+        18,
+        [0, 1, 1, 0, 0, 1],
+        "Gu",
+        "蠱",
+        &["Work on What Has Been Spoiled", "Correcting"],
+        '䷑',
+    ),
+    (
+        19,
+        [1, 1, 0, 0, 0, 0],
+        "Lin",
+        "臨",
+        &["Approach", "Nearing"],
+        '䷒',
+    ),
+    (
+        20,
+        [0, 0, 0, 0, 1, 1],
+        "Guan",
+        "觀",
+        &["Contemplation", "Viewing"],
+        '䷓',
+    ),
+    (
+        21,
+        [1, 0, 0, 1, 0, 1],
+        "Shi He",
+        "噬嗑",
+        &["Biting Through", "Gnawing Bite"],
+        '䷔',
+    ),
+    (
+        22,
+        [1, 0, 1, 0, 0, 1],
+        "Bi",
+        "賁",
+        &["Grace", "Adorning"],
+        '䷕',
+    ),
+    (
+        23,
+        [0, 0, 0, 0, 0, 1],
+        "Bo",
+        "剝",
+        &["Splitting Apart", "Stripping"],
+        '䷖',
+    ),
+    (
+        24,
+        [1, 0, 0, 0, 0, 0],
+        "Fu",
+        "復",
+        &["Return", "Returning"],
+        '䷗',
+    ),
+    (
+        25,
+        [1, 0, 0, 1, 1, 1],
+        "Wu Wang",
+        "無妄",
+        &["Innocence", "Without Embroiling"],
+        '䷘',
+    ),
+    (
+        26,
+        [1, 1, 1, 0, 0, 1],
+        "Da Chu",
+        "大畜",
+        &["The Taming Power of the Great", "Great Accumulating"],
+        '䷙',
+    ),
+    (
+        27,
+        [1, 0, 0, 0, 0, 1],
+        "Yi",
+        "頤",
+        &["The Corners of the Mouth", "Swallowing"],
+        '䷚',
+    ),
+    (
+        28,
+        [0, 1, 1, 1, 1, 0],
+        "Da Guo",
+        "大過",
+        &["Preponderance of the Great", "Great Exceeding"],
+        '䷛',
+    ),
+    (
+        29,
+        [0, 1, 0, 0, 1, 0],
+        "Kan",
+        "坎",
+        &["The Abysmal", "Gorge"],
+        '䷜',
+    ),
+    (
+        30,
+        [1, 0, 1, 1, 0, 1],
+        "Li",
+        "離",
+        &["The Clinging", "Radiance"],
+        '䷝',
+    ),
+    (
+        31,
+        [0, 0, 1, 1, 1, 0],
+        "Xian",
+        "咸",
+        &["Influence", "Conjoining"],
+        '䷞',
+    ),
+    (
+        32,
+        [0, 1, 1, 1, 0, 0],
+        "Heng",
+        "恆",
+        &["Duration", "Persevering"],
+        '䷟',
+    ),
+    (
+        33,
+        [0, 0, 1, 1, 1, 1],
+        "Dun",
+        "遯",
+        &["Retreat", "Retiring"],
+        '䷠',
+    ),
+    (
+        34,
+        [1, 1, 1, 1, 0, 0],
+        "Da Zhuang",
+        "大壯",
+        &["The Power of the Great", "Great Invigorating"],
+        '䷡',
+    ),
+    (
+        35,
+        [0, 0, 0, 1, 0, 1],
+        "Jin",
+        "晉",
+        &["Progress", "Prospering"],
+        '䷢',
+    ),
+    (
+        36,
+        [1, 0, 1, 0, 0, 0],
+        "Ming Yi",
+        "明夷",
+        &["Darkening of the Light", "Brightness Hiding"],
+        '䷣',
+    ),
+    (
+        37,
+        [1, 0, 1, 0, 1, 1],
+        "Jia Ren",
+        "家人",
+        &["The Family", "Dwelling People"],
+        '䷤',
+    ),
+    (
+        38,
+        [1, 1, 0, 1, 0, 1],
+        "Kui",
+        "睽",
+        &["Opposition", "Polarising"],
+        '䷥',
+    ),
+    (
+        39,
+        [0, 0, 1, 0, 1, 0],
+        "Jian",
+        "蹇",
+        &["Obstruction", "Limping"],
+        '䷦',
+    ),
+    (
+        40,
+        [0, 1, 0, 1, 0, 0],
+        "Xie",
+        "解",
+        &["Deliverance", "Taking-Apart"],
+        '䷧',
+    ),
+    (
+        41,
+        [1, 1, 0, 0, 0, 1],
+        "Sun",
+        "損",
+        &["Decrease", "Diminishing"],
+        '䷨',
+    ),
+    (
+        42,
+        [1, 0, 0, 0, 1, 1],
+        "Yi",
+        "益",
+        &["Increase", "Augmenting"],
+        '䷩',
+    ),
+    (
+        43,
+        [1, 1, 1, 1, 1, 0],
+        "Guai",
+        "夬",
+        &["Break-through", "Displacement"],
+        '䷪',
+    ),
+    (
+        44,
+        [0, 1, 1, 1, 1, 1],
+        "Gou",
+        "姤",
+        &["Coming to Meet", "Coupling"],
+        '䷫',
+    ),
+    (
+        45,
+        [0, 0, 0, 1, 1, 0],
+        "Cui",
+        "萃",
+        &["Gathering Together", "Clustering"],
+        '䷬',
+    ),
+    (
+        46,
+        [0, 1, 1, 0, 0, 0],
+        "Sheng",
+        "升",
+        &["Pushing Upward", "Ascending"],
+        '䷭',
+    ),
+    (
+        47,
+        [0, 1, 0, 1, 1, 0],
+        "Kun",
+        "困",
+        &["Oppression", "Confining"],
+        '䷮',
+    ),
+    (
+        48,
+        [0, 1, 1, 0, 1, 0],
+        "Jing",
+        "井",
+        &["The Well", "Welling"],
+        '䷯',
+    ),
+    (
+        49,
+        [1, 0, 1, 1, 1, 0],
+        "Ge",
+        "革",
+        &["Revolution", "Skinning"],
+        '䷰',
+    ),
+    (
+        50,
+        [0, 1, 1, 1, 0, 1],
+        "Ding",
+        "鼎",
+        &["The Cauldron", "Holding"],
+        '䷱',
+    ),
+    (
+        51,
+        [1, 0, 0, 1, 0, 0],
+        "Zhen",
+        "震",
+        &["The Arousing", "Shake"],
+        '䷲',
+    ),
+    (
+        52,
+        [0, 0, 1, 0, 0, 1],
+        "Gen",
+        "艮",
+        &["Keeping Still", "Bound"],
+        '䷳',
+    ),
+    (
+        53,
+        [0, 0, 1, 0, 1, 1],
+        "Jian",
+        "漸",
+        &["Development", "Infiltrating"],
+        '䷴',
+    ),
+    (
+        54,
+        [1, 1, 0, 1, 0, 0],
+        "Gui Mei",
+        "歸妹",
+        &["The Marrying Maiden", "Converting the Maiden"],
+        '䷵',
+    ),
+    (
+        55,
+        [1, 0, 1, 1, 0, 0],
+        "Feng",
+        "豐",
+        &["Abundance", "Abounding"],
+        '䷶',
+    ),
+    (
+        56,
+        [0, 0, 1, 1, 0, 1],
+        "Lü",
+        "旅",
+        &["The Wanderer", "Sojourning"],
+        '䷷',
+    ),
+    (
+        57,
+        [0, 1, 1, 0, 1, 1],
+        "Xun",
+        "巽",
+        &["The Gentle", "Ground"],
+        '䷸',
+    ),
+    (
+        58,
+        [1, 1, 0, 1, 1, 0],
+        "Dui",
+        "兌",
+        &["The Joyous", "Open"],
+        '䷹',
+    ),
+    (
+        59,
+        [0, 1, 0, 0, 1, 1],
+        "Huan",
+        "渙",
+        &["Dispersion", "Dispersing"],
+        '䷺',
+    ),
+    (
+        60,
+        [1, 1, 0, 0, 1, 0],
+        "Jie",
+        "節",
+        &["Limitation", "Articulating"],
+        '䷻',
+    ),
+    (
+        61,
+        [1, 1, 0, 0, 1, 1],
+        "Zhong Fu",
+        "中孚",
+        &["Inner Truth", "Centre Confirming"],
+        '䷼',
+    ),
+    (
+        62,
+        [0, 0, 1, 1, 0, 0],
+        "Xiao Guo",
+        "小過",
+        &["Preponderance of the Small", "Small Exceeding"],
+        '䷽',
+    ),
+    (
+        63,
+        [1, 0, 1, 0, 1, 0],
+        "Ji Ji",
+        "既濟",
+        &["After Completion", "Already Fording"],
+        '䷾',
+    ),
+    (
+        64,
+        [0, 1, 0, 1, 0, 1],
+        "Wei Ji",
+        "未濟",
+        &["Before Completion", "Not Yet Fording"],
+        '䷿',
+    ),
 ];
 
-/// Creates a hexagram from a number and a list of lines.
+/// Creates a hexagram from a number and a list of lines. The names and symbol of the hexagram are
+/// taken from the entry in [`HEXAGRAMS`] with the same lines, so they match the lines even if the
+/// number does not.
 pub fn create_hexagram(number: u8, input_lines: [u8; 6]) -> Hexagram {
     let lines = input_lines.map(Line::from);
-    Hexagram { number, lines }
+    let (_, _, name, chinese_name, english_names, symbol) = *HEXAGRAMS
+        .iter()
+        .find(|(_, entry_lines, ..)| entry_lines.map(Line::from) == lines)
+        .expect("the table of hexagrams contains every combination of lines");
+    Hexagram {
+        number,
+        lines,
+        name,
+        chinese_name,
+        english_names,
+        symbol,
+    }
 }
 
 /// Generate a map of lines to hexagrams for fast lookup.
 fn hexagram_index() -> HashMap<[Line; 6], Hexagram> {
     let mut index = HashMap::new();
-    for (number, lines) in HEXAGRAMS.iter() {
+    for (number, lines, ..) in HEXAGRAMS.iter() {
         let hex = create_hexagram(*number, *lines);
         index.insert(hex.lines, hex);
     }
@@ -497,8 +1085,533 @@ lazy_static! {
     pub static ref HEXAGRAM_INDEX: HashMap<[Line; 6], Hexagram> = hexagram_index();
 }
 
+/// Normalizes a name for lookup by making it lowercase and removing spaces, hyphens, apostrophes,
+/// and diacritics from ü, so that "Ji Ji", "jiji", and "Ji-Ji" are the same name.
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '\'')
+        .map(|c| if c == 'ü' { 'u' } else { c })
+        .collect()
+}
+
+/// Parses a string of 0s and 1s into lines, from bottom to top. A 1 is a closed line.
+fn parse_lines<const N: usize>(s: &str) -> Option<[Line; N]> {
+    if s.len() != N || !s.chars().all(|c| c == '0' || c == '1') {
+        return None;
+    }
+    let mut lines = [Line::Open; N];
+    for (line, c) in lines.iter_mut().zip(s.chars()) {
+        *line = if c == '1' { Line::Closed } else { Line::Open };
+    }
+    Some(lines)
+}
+
+impl FromStr for Trigram {
+    type Err = anyhow::Error;
+
+    /// Parses a trigram from its lines as 0s (open) and 1s (closed) from bottom to top, such as
+    /// "010", from its Unicode symbol, such as "☵", or from its pinyin, Chinese, or English name,
+    /// such as "Kan", "坎", or "Water".
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(lines) = parse_lines::<3>(s) {
+            return Ok(TRIGRAM_INDEX[&lines]);
+        }
+
+        let name = normalize_name(s);
+        TRIGRAMS
+            .iter()
+            .map(create_trigram)
+            .find(|trigram| {
+                trigram.symbol.to_string() == s
+                    || normalize_name(trigram.name) == name
+                    || normalize_name(trigram.chinese_name) == name
+                    || trigram
+                        .english_names
+                        .iter()
+                        .any(|english| normalize_name(english) == name)
+            })
+            .ok_or_else(|| anyhow!("unknown trigram: {:?}", s))
+    }
+}
+
+impl FromStr for Hexagram {
+    type Err = anyhow::Error;
+
+    /// Parses a hexagram from any of the following:
+    ///
+    /// - Its number in King Wen's sequence, such as "63".
+    /// - Its lines as 0s (open) and 1s (closed) from bottom to top, such as "101010".
+    /// - Its Unicode symbol, such as "䷾".
+    /// - Its upper and lower trigrams separated by "over" or a slash, such as "☵ over ☲" or
+    ///   "Kan/Li". Each trigram is parsed as described in [`Trigram::from_str`].
+    /// - Its pinyin, Chinese, or English name, such as "Ji Ji", "既濟", or "After Completion".
+    ///   Some pinyin names are shared by two hexagrams, in which case the number must be used
+    ///   instead.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(lines) = parse_lines::<6>(s) {
+            return Ok(HEXAGRAM_INDEX[&lines]);
+        }
+        if let Ok(number) = s.parse::<u8>() {
+            return Self::from_number(number);
+        }
+
+        // Look for a pair of trigrams, with the upper one first.
+        const OVER: &str = " over ";
+        let pair = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .find(|&i| {
+                s.get(i..i + OVER.len())
+                    .is_some_and(|word| word.eq_ignore_ascii_case(OVER))
+            })
+            .map(|i| (&s[..i], &s[i + OVER.len()..]))
+            .or_else(|| s.split_once('/'));
+        if let Some((upper, lower)) = pair {
+            let upper: Trigram = upper.parse()?;
+            let lower: Trigram = lower.parse()?;
+            return Ok(Self::from_trigrams(&lower, &upper));
+        }
+
+        let name = normalize_name(s);
+        let matches: Vec<Hexagram> = HEXAGRAMS
+            .iter()
+            .map(|(number, lines, ..)| create_hexagram(*number, *lines))
+            .filter(|hexagram| {
+                hexagram.symbol.to_string() == s
+                    || normalize_name(hexagram.name) == name
+                    || normalize_name(hexagram.chinese_name) == name
+                    || hexagram
+                        .english_names
+                        .iter()
+                        .any(|english| normalize_name(english) == name)
+            })
+            .collect();
+        match matches[..] {
+            [hexagram] => Ok(hexagram),
+            [] => bail!("unknown hexagram: {:?}", s),
+            _ => {
+                let numbers: Vec<String> = matches
+                    .iter()
+                    .map(|hexagram| hexagram.number.to_string())
+                    .collect();
+                bail!(
+                    "ambiguous hexagram {:?}: it could be any of {}",
+                    s,
+                    numbers.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// A reference to one of the texts of a hexagram.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TextReference {
+    /// The judgment of the given hexagram.
+    Judgment {
+        /// The number of the hexagram.
+        hexagram: u8,
+    },
+
+    /// The statement of a single line of the given hexagram.
+    Line {
+        /// The number of the hexagram.
+        hexagram: u8,
+
+        /// The zero-based index of the line, counting from the bottom.
+        line: usize,
+    },
+
+    /// The additional statement of hexagrams 1 and 2 used when all their lines are changing.
+    AllLines {
+        /// The number of the hexagram.
+        hexagram: u8,
+    },
+}
+
+impl Display for TextReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextReference::Judgment { hexagram } => write!(f, "Hexagram {}, judgment", hexagram),
+            TextReference::Line { hexagram, line } => {
+                write!(f, "Hexagram {}, line {}", hexagram, line + 1)
+            }
+            TextReference::AllLines { hexagram } => write!(f, "Hexagram {}, all lines", hexagram),
+        }
+    }
+}
+
+/// A text that applies to a reading, according to the traditional rules for interpreting the
+/// changing lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct ApplicableText {
+    /// The text to consult.
+    pub reference: TextReference,
+
+    /// Whether this text is the primary one. Secondary texts should be read in light of the primary
+    /// text.
+    pub primary: bool,
+}
+
+/// The record of a single split of the yarrow stalks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct YarrowSplit {
+    /// The number of stalks before the split.
+    pub stalks: u8,
+
+    /// The number of stalks in the left pile after the split, including the stalk that is set
+    /// aside.
+    pub left: u8,
+
+    /// The number of stalks in the right pile after the split.
+    pub right: u8,
+
+    /// The remainder counted from the left pile, after setting one stalk aside.
+    pub left_remainder: u8,
+
+    /// The remainder counted from the right pile.
+    pub right_remainder: u8,
+
+    /// The number of groups of four stalks counted from both piles.
+    pub groups: u8,
+}
+
+impl YarrowSplit {
+    /// Returns the number of stalks left for the next split, once the stalk set aside and the
+    /// remainders have been removed.
+    pub fn remaining(&self) -> Result<u8> {
+        self.stalks
+            .checked_sub(self.left_remainder)
+            .and_then(|stalks| stalks.checked_sub(self.right_remainder))
+            .and_then(|stalks| stalks.checked_sub(1))
+            .ok_or_else(|| anyhow!("invalid yarrow stalk split: {:?}", self))
+    }
+}
+
+/// The details of how the lines of a reading were cast, kept so that the reading can be audited
+/// and replayed.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "method", rename_all = "kebab-case")]
+pub enum Casting {
+    /// The three coin tosses of each line, from bottom to top. Each toss is worth 2 or 3.
+    Coin {
+        /// The tosses of each line.
+        tosses: [[u8; 3]; 6],
+    },
+
+    /// The three splits of the yarrow stalks of each line, from bottom to top.
+    YarrowStalks {
+        /// The splits of each line.
+        splits: [[YarrowSplit; 3]; 6],
+    },
+
+    /// The marble drawn for each line, from bottom to top. Marbles are numbered from 1 to 16.
+    SixteenMarbles {
+        /// The marble drawn for each line.
+        marbles: [u8; 6],
+    },
+
+    /// The two tosses of the two coins of each line, from bottom to top. Each toss is worth 2 or 3,
+    /// and the first two values belong to the first toss.
+    TwoCoin {
+        /// The tosses of each line.
+        tosses: [[u8; 4]; 6],
+    },
+
+    /// The rolls of the dice: two eight-sided dice for the trigrams, using their Early Heaven
+    /// numbers, and a six-sided die for the changing line.
+    Dice {
+        /// The Early Heaven number of the lower trigram, from 1 to 8.
+        lower: u8,
+
+        /// The Early Heaven number of the upper trigram, from 1 to 8.
+        upper: u8,
+
+        /// The changing line, from 1 (bottom) to 6 (top).
+        line: u8,
+    },
+
+    /// The numbers of the time at which the reading was cast in the lunar calendar, from which the
+    /// plum blossom method derives the trigrams and the changing line.
+    PlumBlossom {
+        /// The earthly branch of the year, from 1 (Zi) to 12 (Hai).
+        year: u8,
+
+        /// The lunar month, from 1 to 12.
+        month: u8,
+
+        /// The day of the lunar month, from 1 to 30.
+        day: u8,
+
+        /// The earthly branch of the double hour, from 1 (Zi, 23:00 to 01:00) to 12 (Hai).
+        hour: u8,
+    },
+
+    /// The two numbers given by the person asking, from which the plum blossom method derives the
+    /// trigrams and the changing line.
+    PlumBlossomNumbers {
+        /// The number giving the upper trigram.
+        first: u32,
+
+        /// The number giving the lower trigram.
+        second: u32,
+    },
+}
+
+/// Returns the value of each line of the hexagram formed by the given Early Heaven trigrams, with
+/// the given line, from 1 (bottom) to 6 (top), changing.
+fn trigram_line_values(lower: u8, upper: u8, line: u8) -> Result<[u8; 6]> {
+    if !(1..=6).contains(&line) {
+        bail!("invalid changing line: {}", line);
+    }
+    let lower = early_heaven_trigram(lower)?;
+    let upper = early_heaven_trigram(upper)?;
+    let mut values = [0; 6];
+    for (i, closed) in lower.iter().chain(upper.iter()).enumerate() {
+        let changing = i + 1 == line as usize;
+        values[i] = match (closed, changing) {
+            (1, false) => 7,
+            (1, true) => 9,
+            (_, false) => 8,
+            (_, true) => 6,
+        };
+    }
+    Ok(values)
+}
+
+/// Returns the Early Heaven numbers of the lower and upper trigrams and the changing line derived
+/// by the plum blossom method from the given sums: the lower and upper sums modulo 8 give the
+/// trigrams, and the line sum modulo 6 gives the changing line. A remainder of 0 counts as 8 or 6.
+fn plum_blossom_trigrams(lower_sum: u32, upper_sum: u32, line_sum: u32) -> (u8, u8, u8) {
+    let modulo = |sum: u32, n: u32| match sum % n {
+        0 => n as u8,
+        remainder => remainder as u8,
+    };
+    (
+        modulo(lower_sum, 8),
+        modulo(upper_sum, 8),
+        modulo(line_sum, 6),
+    )
+}
+
+/// Returns the trigrams and the changing line derived from a time in the lunar calendar. The upper
+/// trigram comes from the sum of the year, month, and day, and the lower trigram and the changing
+/// line from that sum plus the hour.
+fn plum_blossom_time(year: u8, month: u8, day: u8, hour: u8) -> (u8, u8, u8) {
+    let upper_sum = year as u32 + month as u32 + day as u32;
+    let lower_sum = upper_sum + hour as u32;
+    plum_blossom_trigrams(lower_sum, upper_sum, lower_sum)
+}
+
+/// Returns the trigrams and the changing line derived from two numbers. The first number gives the
+/// upper trigram, the second the lower trigram, and their sum the changing line.
+fn plum_blossom_numbers(first: u32, second: u32) -> (u8, u8, u8) {
+    let line_sum = (first as u64 + second as u64) % 6;
+    plum_blossom_trigrams(second, first, line_sum as u32)
+}
+
+impl Casting {
+    /// Returns the reading method with which the lines were cast.
+    pub fn method(&self) -> ReadingMethod {
+        match self {
+            Casting::Coin { .. } => ReadingMethod::Coin,
+            Casting::YarrowStalks { .. } => ReadingMethod::YarrowStalks,
+            Casting::SixteenMarbles { .. } => ReadingMethod::SixteenMarbles,
+            Casting::TwoCoin { .. } => ReadingMethod::TwoCoin,
+            Casting::Dice { .. } => ReadingMethod::Dice,
+            Casting::PlumBlossom { .. } | Casting::PlumBlossomNumbers { .. } => {
+                ReadingMethod::PlumBlossom
+            }
+        }
+    }
+
+    /// Replays the casting and returns the value of each line, from bottom to top. Returns an error
+    /// if the recorded casting is not consistent with the rules of its method.
+    pub fn replay(&self) -> Result<[u8; 6]> {
+        let mut values = [0; 6];
+        match self {
+            Casting::Coin { tosses } => {
+                for (i, line) in tosses.iter().enumerate() {
+                    if line.iter().any(|toss| !(2..=3).contains(toss)) {
+                        bail!("invalid coin tosses for line {}: {:?}", i + 1, line);
+                    }
+                    values[i] = line.iter().sum();
+                }
+            }
+            Casting::YarrowStalks { splits } => {
+                for (i, line) in splits.iter().enumerate() {
+                    let mut num_stalks = YARROW_STALKS;
+                    for split in line {
+                        let expected = yarrow_stalk_split_with(num_stalks, split.right)?;
+                        if *split != expected {
+                            bail!(
+                                "inconsistent yarrow stalk split for line {}: {:?}",
+                                i + 1,
+                                split
+                            );
+                        }
+                        num_stalks = split.remaining()?;
+                    }
+                    values[i] = line[2].groups;
+                }
+            }
+            Casting::SixteenMarbles { marbles } => {
+                for (i, marble) in marbles.iter().enumerate() {
+                    values[i] = marble_value(*marble)
+                        .ok_or_else(|| anyhow!("invalid marble for line {}: {}", i + 1, marble))?;
+                }
+            }
+            Casting::TwoCoin { tosses } => {
+                for (i, line) in tosses.iter().enumerate() {
+                    if line.iter().any(|toss| !(2..=3).contains(toss)) {
+                        bail!("invalid coin tosses for line {}: {:?}", i + 1, line);
+                    }
+                    values[i] = two_coin_value(line);
+                }
+            }
+            Casting::Dice { lower, upper, line } => {
+                values = trigram_line_values(*lower, *upper, *line)?;
+            }
+            Casting::PlumBlossom {
+                year,
+                month,
+                day,
+                hour,
+            } => {
+                LunarTime::new(*year, *month, *day, *hour)?;
+                let (lower, upper, line) = plum_blossom_time(*year, *month, *day, *hour);
+                values = trigram_line_values(lower, upper, line)?;
+            }
+            Casting::PlumBlossomNumbers { first, second } => {
+                if *first == 0 || *second == 0 {
+                    bail!("the numbers of the plum blossom method must be positive");
+                }
+                let (lower, upper, line) = plum_blossom_numbers(*first, *second);
+                values = trigram_line_values(lower, upper, line)?;
+            }
+        }
+        Ok(values)
+    }
+
+    /// Prints the casting of each line, from bottom to top.
+    pub fn print(&self) {
+        match self {
+            Casting::Coin { tosses } => {
+                for (i, line) in tosses.iter().enumerate() {
+                    println!(
+                        "Line {}: {} + {} + {} = {}",
+                        i + 1,
+                        line[0],
+                        line[1],
+                        line[2],
+                        line.iter().sum::<u8>()
+                    );
+                }
+            }
+            Casting::YarrowStalks { splits } => {
+                for (i, line) in splits.iter().enumerate() {
+                    let details: Vec<String> = line
+                        .iter()
+                        .map(|split| {
+                            format!(
+                                "{} split {}/{}, remainders {}+{}",
+                                split.stalks,
+                                split.left,
+                                split.right,
+                                split.left_remainder,
+                                split.right_remainder
+                            )
+                        })
+                        .collect();
+                    println!(
+                        "Line {}: {} = {}",
+                        i + 1,
+                        details.join("; "),
+                        line[2].groups
+                    );
+                }
+            }
+            Casting::SixteenMarbles { marbles } => {
+                for (i, marble) in marbles.iter().enumerate() {
+                    println!(
+                        "Line {}: marble {} = {}",
+                        i + 1,
+                        marble,
+                        marble_value(*marble).unwrap_or_default()
+                    );
+                }
+            }
+            Casting::TwoCoin { tosses } => {
+                for (i, line) in tosses.iter().enumerate() {
+                    println!(
+                        "Line {}: {} + {}, then {} + {} = {}",
+                        i + 1,
+                        line[0],
+                        line[1],
+                        line[2],
+                        line[3],
+                        two_coin_value(line)
+                    );
+                }
+            }
+            Casting::Dice { lower, upper, line } => {
+                println!("Lower trigram: {}", lower);
+                println!("Upper trigram: {}", upper);
+                println!("Changing line: {}", line);
+            }
+            Casting::PlumBlossom {
+                year,
+                month,
+                day,
+                hour,
+            } => {
+                let (lower, upper, line) = plum_blossom_time(*year, *month, *day, *hour);
+                println!(
+                    "Year {} ({}), lunar month {}, day {}, hour {} ({})",
+                    year,
+                    branch_name(*year).unwrap_or_default(),
+                    month,
+                    day,
+                    hour,
+                    branch_name(*hour).unwrap_or_default()
+                );
+                println!(
+                    "Upper trigram: ({} + {} + {}) mod 8 = {}",
+                    year, month, day, upper
+                );
+                println!(
+                    "Lower trigram: ({} + {} + {} + {}) mod 8 = {}",
+                    year, month, day, hour, lower
+                );
+                println!(
+                    "Changing line: ({} + {} + {} + {}) mod 6 = {}",
+                    year, month, day, hour, line
+                );
+            }
+            Casting::PlumBlossomNumbers { first, second } => {
+                let (lower, upper, line) = plum_blossom_numbers(*first, *second);
+                println!("Numbers {} and {}", first, second);
+                println!("Upper trigram: {} mod 8 = {}", first, upper);
+                println!("Lower trigram: {} mod 8 = {}", second, lower);
+                println!("Changing line: ({} + {}) mod 6 = {}", first, second, line);
+            }
+        }
+    }
+}
+
+/// Serializes a set of line indices as a sorted list.
+fn serialize_sorted<S: Serializer>(set: &HashSet<usize>, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut values: Vec<&usize> = set.iter().collect();
+    values.sort();
+    serializer.collect_seq(values)
+}
+
 /// A reading of the I Ching.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct Reading {
     /// The question asked of the I Ching.
     question: String,
@@ -510,40 +1623,305 @@ pub struct Reading {
     future: Option<Hexagram>,
 
     /// The lines that are changing between the present and future hexagrams.
+    #[serde(serialize_with = "serialize_sorted")]
     changing_lines: HashSet<usize>,
+
+    /// The value of each line, from bottom to top.
+    throws: [u8; 6],
+
+    /// The details of how each line was cast, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    casting: Option<Casting>,
+
+    /// The reason why the numbers were drawn from a fallback source of randomness, if they were.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fallback: Option<String>,
+
+    /// The batches of signed numbers from which the reading was cast, if random.org signed them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    signatures: Vec<SignedRandom>,
 }
 
 impl Reading {
-    /// Prints the reading to the console.
+    /// Returns the question asked of the I Ching.
+    pub fn question(&self) -> &str {
+        &self.question
+    }
+
+    /// Returns the present hexagram.
+    pub fn present(&self) -> &Hexagram {
+        &self.present
+    }
+
+    /// Returns the future hexagram, if any.
+    pub fn future(&self) -> Option<&Hexagram> {
+        self.future.as_ref()
+    }
+
+    /// Returns the zero-based indices of the lines that are changing between the present and future
+    /// hexagrams.
+    pub fn changing_lines(&self) -> &HashSet<usize> {
+        &self.changing_lines
+    }
+
+    /// Returns the value of each line, from bottom to top: 6 for old yin, 7 for young yang, 8 for
+    /// young yin, and 9 for old yang. These are the same values from which the reading was built.
+    pub fn line_values(&self) -> [u8; 6] {
+        self.throws
+    }
+
+    /// Returns the details of how each line was cast. A casting is recorded for every generated
+    /// reading, whatever the method. It is `None` only for readings built from the values of the
+    /// lines, with [`reading_from_throws`] or by manual entry.
+    pub fn casting(&self) -> Option<&Casting> {
+        self.casting.as_ref()
+    }
+
+    /// Returns the reason why some or all of the numbers used to cast the reading were drawn from a
+    /// fallback source of randomness, if they were.
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+
+    /// Records the reason why a fallback source of randomness was used to cast the reading.
+    pub(crate) fn set_fallback(&mut self, reason: Option<String>) {
+        self.fallback = reason;
+    }
+
+    /// Returns the batches of signed numbers from which the reading was cast. The list is empty
+    /// unless the numbers were requested from random.org with an API key.
+    pub fn signatures(&self) -> &[SignedRandom] {
+        &self.signatures
+    }
+
+    /// Records the batches of signed numbers from which the reading was cast.
+    pub(crate) fn set_signatures(&mut self, signatures: Vec<SignedRandom>) {
+        self.signatures = signatures;
+    }
+
+    /// Returns the texts that apply to the reading according to the rules given by Zhu Xi, which
+    /// depend on the number of changing lines:
+    ///
+    /// - None: the judgment of the present hexagram.
+    /// - One: the changing line of the present hexagram.
+    /// - Two: both changing lines of the present hexagram, the upper one being primary.
+    /// - Three: the judgments of the present hexagram, which is primary, and the future hexagram.
+    /// - Four: the two unchanging lines of the future hexagram, the lower one being primary.
+    /// - Five: the only unchanging line of the future hexagram.
+    /// - Six: the judgment of the future hexagram, except for hexagrams 1 and 2, which use their
+    ///   additional statement ("use nines" and "use sixes").
+    ///
+    /// The primary text is always the first one in the list.
+    pub fn applicable_texts(&self) -> Vec<ApplicableText> {
+        let present = self.present.number;
+        let future = self.future.as_ref().map_or(present, |hex| hex.number);
+        let mut changing: Vec<usize> = self.changing_lines.iter().copied().collect();
+        changing.sort();
+        let unchanging: Vec<usize> = (0..6)
+            .filter(|i| !self.changing_lines.contains(i))
+            .collect();
+
+        let primary = |reference| ApplicableText {
+            reference,
+            primary: true,
+        };
+        let secondary = |reference| ApplicableText {
+            reference,
+            primary: false,
+        };
+        match changing.len() {
+            0 => vec![primary(TextReference::Judgment { hexagram: present })],
+            1 => vec![primary(TextReference::Line {
+                hexagram: present,
+                line: changing[0],
+            })],
+            2 => vec![
+                primary(TextReference::Line {
+                    hexagram: present,
+                    line: changing[1],
+                }),
+                secondary(TextReference::Line {
+                    hexagram: present,
+                    line: changing[0],
+                }),
+            ],
+            3 => vec![
+                primary(TextReference::Judgment { hexagram: present }),
+                secondary(TextReference::Judgment { hexagram: future }),
+            ],
+            4 => vec![
+                primary(TextReference::Line {
+                    hexagram: future,
+                    line: unchanging[0],
+                }),
+                secondary(TextReference::Line {
+                    hexagram: future,
+                    line: unchanging[1],
+                }),
+            ],
+            5 => vec![primary(TextReference::Line {
+                hexagram: future,
+                line: unchanging[0],
+            })],
+            _ if present <= 2 => vec![primary(TextReference::AllLines { hexagram: present })],
+            _ => vec![primary(TextReference::Judgment { hexagram: future })],
+        }
+    }
+
+    /// Prints the reading to the console using the bundled translation.
     pub fn print(&self) {
+        self.print_with_texts(&BUILTIN_TEXT_PACK);
+    }
+
+    /// The text printed in place of a text missing from the text pack.
+    const MISSING_TEXT: &'static str = "(missing from the text pack)";
+
+    /// Prints the reading to the console using the texts from the given text pack.
+    pub fn print_with_texts(&self, texts: &TextPack) {
         if !self.question.is_empty() {
             println!("Question: {}", self.question);
         }
+        if let Some(casting) = &self.casting {
+            println!("\nCasting\n");
+            casting.print();
+        }
+        if let Some(reason) = &self.fallback {
+            println!(
+                "\nThe reading was completed with a fallback source of randomness: {}",
+                reason
+            );
+        }
+        if !self.signatures.is_empty() {
+            let serials: Vec<String> = self
+                .signatures
+                .iter()
+                .map(|signed| signed.serial_number.to_string())
+                .collect();
+            println!(
+                "\nThe numbers were signed by random.org (serial numbers {})",
+                serials.join(", ")
+            );
+        }
         println!("\nPresent Hexagram\n");
         self.present.print(Some(&self.changing_lines));
-
-        match &self.future {
-            Some(hex) => {
-                println!("\nFuture Hexagram\n");
-                hex.print(None);
+        let text = |reference| texts.text(&reference).unwrap_or(Self::MISSING_TEXT);
+        let present = self.present.number;
+        println!(
+            "\nJudgment: {}",
+            text(TextReference::Judgment { hexagram: present })
+        );
+
+        if !self.changing_lines.is_empty() {
+            println!("\nChanging Lines");
+            let mut changing_lines: Vec<&usize> = self.changing_lines.iter().collect();
+            changing_lines.sort();
+            for index in changing_lines {
+                let reference = TextReference::Line {
+                    hexagram: present,
+                    line: *index,
+                };
+                println!("\nLine {}: {}", index + 1, text(reference));
+            }
+            if self.changing_lines.len() == 6 {
+                if let Some(all_lines) = texts.text(&TextReference::AllLines { hexagram: present })
+                {
+                    println!("\nAll lines: {}", all_lines);
+                }
             }
-            None => (),
         }
+
+        if let Some(hex) = &self.future {
+            println!("\nFuture Hexagram\n");
+            hex.print(None);
+            println!(
+                "\nJudgment: {}",
+                text(TextReference::Judgment {
+                    hexagram: hex.number
+                })
+            );
+        }
+
+        println!("\nInterpretation\n");
+        print!("{}", self.interpretation(texts));
+    }
+
+    /// Formats the texts that apply to the reading, one per line, using the texts from the given
+    /// text pack.
+    pub(crate) fn interpretation(&self, texts: &TextPack) -> String {
+        self.applicable_texts()
+            .into_iter()
+            .map(|text| {
+                let label = if text.primary { "primary" } else { "secondary" };
+                format!(
+                    "{} ({}): {}\n",
+                    text.reference,
+                    label,
+                    texts.text(&text.reference).unwrap_or(Self::MISSING_TEXT)
+                )
+            })
+            .collect()
     }
 }
 
 /// The method used to generate the reading.
-#[derive(Clone, Debug, ValueEnum)]
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ReadingMethod {
     /// A method using yarrow stalks. This is the traditional method, which is more involved. The
     /// probabilities that a yin or yang line will transform are not equal. This asymmetry reflects
     /// the traditional understanding of the intrinsic tendency of yin towards stability and of yang
-    /// towards transformation.
+    /// towards transformation. The probabilities of an old yin, young yang, young yin, and old yang
+    /// line are traditionally given as 1/16, 5/16, 7/16, and 3/16. Since the actual probabilities
+    /// depend on how the stalks are split, they are only close to these values.
     YarrowStalks,
 
     /// A method using random draws from a coin. This is a simplified method, which is easier to
-    /// perform. The probabilities that a yin or yang line will transform are equal.
+    /// perform. The probabilities that a yin or yang line will transform are equal. The
+    /// probabilities of an old yin, young yang, young yin, and old yang line are 1/8, 3/8, 3/8, and
+    /// 1/8.
     Coin,
+
+    /// A method drawing one marble from a bag of sixteen for each line: one marble for old yin,
+    /// five for young yang, seven for young yin, and three for old yang. It is as simple as the
+    /// coin method but keeps the probabilities of the yarrow stalks: 1/16, 5/16, 7/16, and 3/16.
+    /// Tossing four coins, whose sixteen outcomes are assigned to the lines in the same
+    /// proportions, is equivalent.
+    SixteenMarbles,
+
+    /// A method tossing two distinct coins twice for each line. The first coin of the first toss
+    /// gives a yang line on heads and a yin line on tails. A yang line changes if exactly one of
+    /// the other three coins shows heads, and a yin line changes if all three do. This keeps the
+    /// probabilities of the yarrow stalks: 1/16, 5/16, 7/16, and 3/16.
+    TwoCoin,
+
+    /// A method rolling two eight-sided dice for the lower and upper trigrams and a six-sided die
+    /// for the changing line. Exactly one line changes, so each line is old yin or old yang with a
+    /// probability of 1/12 and young yang or young yin with a probability of 5/12.
+    Dice,
+
+    /// The plum blossom (Mei Hua) method, which derives the trigrams and the changing line from the
+    /// time at which the reading is cast in the lunar calendar, or from two numbers given by the
+    /// person asking, instead of drawing random numbers. As with the dice method, exactly one line
+    /// changes. Over a uniformly random time, each line is old yin or old yang with a probability
+    /// close to 1/12 and young yang or young yin with a probability close to 5/12.
+    PlumBlossom,
+}
+
+impl ReadingMethod {
+    /// Returns the probability of each line value, from 6 (old yin) to 9 (old yang). For the dice
+    /// and plum blossom methods, which change exactly one line, these are the probabilities of each
+    /// line taken on its own.
+    pub fn line_probabilities(&self) -> [f64; 4] {
+        match self {
+            ReadingMethod::YarrowStalks
+            | ReadingMethod::SixteenMarbles
+            | ReadingMethod::TwoCoin => [1.0 / 16.0, 5.0 / 16.0, 7.0 / 16.0, 3.0 / 16.0],
+            ReadingMethod::Coin => [1.0 / 8.0, 3.0 / 8.0, 3.0 / 8.0, 1.0 / 8.0],
+            ReadingMethod::Dice | ReadingMethod::PlumBlossom => {
+                [1.0 / 12.0, 5.0 / 12.0, 5.0 / 12.0, 1.0 / 12.0]
+            }
+        }
+    }
 }
 
 impl Display for ReadingMethod {
@@ -551,18 +1929,37 @@ impl Display for ReadingMethod {
         match self {
             ReadingMethod::YarrowStalks => write!(f, "yarrow-stalks"),
             ReadingMethod::Coin => write!(f, "coin"),
+            ReadingMethod::SixteenMarbles => write!(f, "sixteen-marbles"),
+            ReadingMethod::TwoCoin => write!(f, "two-coin"),
+            ReadingMethod::Dice => write!(f, "dice"),
+            ReadingMethod::PlumBlossom => write!(f, "plum-blossom"),
         }
     }
 }
 
 /// The method used to generate random numbers.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RandomnessMode {
     /// Generate truly random numbers using random.org.
     Random,
 
     /// Generate pseudo-random numbers using the system's random number generator.
     Pseudorandom,
+
+    /// Generate random numbers using the random number generator of the operating system.
+    System,
+
+    /// Read random bytes from a file, such as a hardware random number generator.
+    Stream,
+
+    /// The lines were cast by hand and entered manually.
+    #[value(skip)]
+    Manual,
+
+    /// The lines were derived from a date or from numbers, without drawing random numbers.
+    #[value(skip)]
+    Deterministic,
 }
 
 impl Display for RandomnessMode {
@@ -570,71 +1967,83 @@ impl Display for RandomnessMode {
         match self {
             RandomnessMode::Random => write!(f, "random"),
             RandomnessMode::Pseudorandom => write!(f, "pseudorandom"),
+            RandomnessMode::System => write!(f, "system"),
+            RandomnessMode::Stream => write!(f, "stream"),
+            RandomnessMode::Manual => write!(f, "manual"),
+            RandomnessMode::Deterministic => write!(f, "deterministic"),
         }
     }
 }
 
-/// The URL to use for the coin method.
-static COIN_READING_URL: &str =
-    "https://www.random.org/integers/?num=1&min=2&max=3&col=1&base=10&format=plain&rnd=new";
+/// Generates a line using the given source of randomness. Returns the three throws of the coin.
+fn coin_line(entropy: &mut dyn EntropySource) -> Result<[u8; 3]> {
+    // Throw the coin three times. One side of the coin is assigned a value of 2 and the other a
+    // value of 3. The sum of the three throws is the value of the line, ranging from 6 to 9.
+    let throw1 = entropy.integer(2, 3)?;
+    let throw2 = entropy.integer(2, 3)?;
+    let throw3 = entropy.integer(2, 3)?;
+    Ok([throw1, throw2, throw3])
+}
 
-/// Generates a random coin throw using random.org.
-fn random_coin_throw() -> Result<u8> {
-    let body = reqwest::blocking::get(COIN_READING_URL)?.text()?;
-    let draw: u8 = body.trim().parse()?;
-    Ok(draw)
+/// Generates a reading using the given source of randomness.
+fn coin_reading(entropy: &mut dyn EntropySource) -> Result<Casting> {
+    let mut tosses = [[0; 3]; 6];
+    for line in tosses.iter_mut() {
+        *line = coin_line(entropy)?;
+    }
+    Ok(Casting::Coin { tosses })
 }
 
-/// Generates a pseudo-random coin throw using the system's random number generator.
-fn pseudo_random_coin_throw() -> u8 {
-    let mut rng = rand::thread_rng();
-    rng.gen_range(2..4)
+/// Returns the value of the line given by the marble with the given number, from 1 to 16.
+fn marble_value(marble: u8) -> Option<u8> {
+    match marble {
+        1 => Some(6),
+        2..=6 => Some(7),
+        7..=13 => Some(8),
+        14..=16 => Some(9),
+        _ => None,
+    }
 }
 
-/// Generates a coin throw based on the given randomness mode.
-fn coin_draw(randomness: RandomnessMode) -> Result<u8> {
-    match randomness {
-        RandomnessMode::Random => random_coin_throw(),
-        RandomnessMode::Pseudorandom => Ok(pseudo_random_coin_throw()),
+/// Generates a reading by drawing a marble from the bag of sixteen for each line. The marble is put
+/// back into the bag after each draw.
+fn sixteen_marbles_reading(entropy: &mut dyn EntropySource) -> Result<Casting> {
+    let mut marbles = [0; 6];
+    for marble in marbles.iter_mut() {
+        *marble = entropy.integer(1, 16)?;
     }
+    Ok(Casting::SixteenMarbles { marbles })
 }
 
-/// Generates a line using the given randomness mode.
-fn coin_line(randomness: RandomnessMode) -> Result<u8> {
-    // Throw the coin three times. One side of the coin is assigned a value of 2 and the other a
-    // value of 3. The sum of the three throws is the value of the line, ranging from 6 to 9.
-    let throw1 = coin_draw(randomness)?;
-    let throw2 = coin_draw(randomness)?;
-    let throw3 = coin_draw(randomness)?;
-    Ok(throw1 + throw2 + throw3)
+/// Returns the value of the line given by the two tosses of the two coins. A toss worth 3 is heads.
+fn two_coin_value(tosses: &[u8; 4]) -> u8 {
+    let heads = tosses[1..].iter().filter(|toss| **toss == 3).count();
+    match (tosses[0] == 3, heads) {
+        (true, 1) => 9,
+        (true, _) => 7,
+        (false, 3) => 6,
+        (false, _) => 8,
+    }
 }
 
-/// Generates a reading using the given randomness mode.
-fn coin_reading(randomness: RandomnessMode) -> Result<Vec<u8>> {
-    vec![0; 6].iter().map(|_| coin_line(randomness)).collect()
+/// Generates a reading by tossing two coins twice for each line.
+fn two_coin_reading(entropy: &mut dyn EntropySource) -> Result<Casting> {
+    let mut tosses = [[0; 4]; 6];
+    for line in tosses.iter_mut() {
+        for toss in line.iter_mut() {
+            *toss = entropy.integer(2, 3)?;
+        }
+    }
+    Ok(Casting::TwoCoin { tosses })
 }
 
-/// Generates a random number using random.org for use in the yarrow stalks method. The number
-/// represents the number of stalks on the right pile after the split.
-fn random_yarrow_stalks_split(num_stalks: u8) -> Result<u8> {
-    // The max number to draw should be the number of stalks minus 2 so that the left pile always
-    // has at least two stalks, since one will be removed from it.
-    let url = format!(
-        "https://www.random.org/integers/?num=1&min=1&max={}&col=1&base=10&format=plain&rnd=new",
-        num_stalks - 2
-    );
-    let body = reqwest::blocking::get(url)?.text()?;
-    let split: u8 = body.trim().parse()?;
-    Ok(split)
-}
-
-/// Generates a random number using the system's random number generator for use in the yarrow
-/// stalks method. The number represents the number of stalks on the right pile after the split.
-fn pseudo_random_yarrow_stalks_split(num_stalks: u8) -> Result<u8> {
-    // The max number to draw should be the number of stalks minus 2 so that the left pile always
-    // has at least two stalks, since one will be removed from it.
-    let mut rng = rand::thread_rng();
-    Ok(rng.gen_range(1..num_stalks - 1))
+/// Generates a reading by rolling two eight-sided dice and a six-sided die.
+fn dice_reading(entropy: &mut dyn EntropySource) -> Result<Casting> {
+    Ok(Casting::Dice {
+        lower: entropy.integer(1, 8)?,
+        upper: entropy.integer(1, 8)?,
+        line: entropy.integer(1, 6)?,
+    })
 }
 
 /// Counts the reminder from a pile.
@@ -647,66 +2056,120 @@ fn pile_reminder(pile_size: u8) -> u8 {
     }
 }
 
-/// Splits the yarrow stalks into two piles, sets one stalk aside, and counts the remainder from the
-/// two piles. This procedure is repeated three times to generate a line from the reading. Returns
-/// the remaining stalks and the number of groups of four stalks that were counted.
-fn yarrow_stalk_split(num_stalks: u8, randomness: RandomnessMode) -> Result<(u8, u8)> {
-    // Split the stalks into two piles.
-    let right = match randomness {
-        RandomnessMode::Random => random_yarrow_stalks_split(num_stalks)?,
-        RandomnessMode::Pseudorandom => pseudo_random_yarrow_stalks_split(num_stalks)?,
-    };
+/// The number of yarrow stalks used at the start of each line.
+const YARROW_STALKS: u8 = 49;
+
+/// Sets one stalk aside from the left pile and counts the remainder from the two piles, given the
+/// number of stalks and the size of the right pile after the split.
+fn yarrow_stalk_split_with(num_stalks: u8, right: u8) -> Result<YarrowSplit> {
+    // The left pile needs at least two stalks, since one will be removed from it.
+    if num_stalks < 3 || right < 1 || right > num_stalks - 2 {
+        bail!(
+            "invalid split of {} stalks: right pile of {}",
+            num_stalks,
+            right
+        );
+    }
     let left = num_stalks - right;
 
     // Take one stalk from the left pile and set it aside.
-    let left = left - 1;
+    let left_after = left - 1;
 
     // Count the groups of four and the remainder from the right pile.
     let right_reminder = pile_reminder(right);
     let right_groups = (right - right_reminder) / 4;
 
     // Count the groups of four and the remainder from the left pile.
-    let left_reminder = pile_reminder(left);
-    let left_groups = (left - left_reminder) / 4;
-
-    // The remainders and the first stalk from the left pile are set aside.
-    let new_num_stalks = num_stalks - right_reminder - left_reminder - 1;
-    Ok((new_num_stalks, left_groups + right_groups))
+    let left_reminder = pile_reminder(left_after);
+    let left_groups = (left_after - left_reminder) / 4;
+
+    Ok(YarrowSplit {
+        stalks: num_stalks,
+        left,
+        right,
+        left_remainder: left_reminder,
+        right_remainder: right_reminder,
+        groups: left_groups + right_groups,
+    })
 }
 
-/// Generates a line for a reading using the yarrow stalks method.
-fn yarrow_stalk_line(randomness: RandomnessMode) -> Result<u8> {
-    // Start with 49 stalks.
-    let num_stalks = 49;
+/// Splits the yarrow stalks into two piles, sets one stalk aside, and counts the remainder from the
+/// two piles. This procedure is repeated three times to generate a line from the reading.
+fn yarrow_stalk_split(num_stalks: u8, entropy: &mut dyn EntropySource) -> Result<YarrowSplit> {
+    // Split the stalks into two piles. The number drawn is the number of stalks on the right pile.
+    // The max number to draw should be the number of stalks minus 2 so that the left pile always
+    // has at least two stalks, since one will be removed from it.
+    let right = entropy.integer(1, num_stalks - 2)?;
+    yarrow_stalk_split_with(num_stalks, right)
+}
 
-    // Split and count the remainders three times.
-    let (num_stalks, _) = yarrow_stalk_split(num_stalks, randomness)?;
-    let (num_stalks, _) = yarrow_stalk_split(num_stalks, randomness)?;
-    let (_, groups) = yarrow_stalk_split(num_stalks, randomness)?;
+/// Generates a line for a reading using the yarrow stalks method. Returns the three splits. The
+/// number of groups of four after the third split determines the line.
+fn yarrow_stalk_line(entropy: &mut dyn EntropySource) -> Result<[YarrowSplit; 3]> {
+    // Start with 49 stalks, then split and count the remainders three times. The remainders and the
+    // first stalk from the left pile are set aside after each split.
+    let first = yarrow_stalk_split(YARROW_STALKS, entropy)?;
+    let second = yarrow_stalk_split(first.remaining()?, entropy)?;
+    let third = yarrow_stalk_split(second.remaining()?, entropy)?;
+    Ok([first, second, third])
+}
 
-    // The number of groups of four after the third split determines the line.
-    Ok(groups)
+/// Generates a reading using the given source of randomness and the yarrow stalks method.
+fn yarrow_stalk_reading(entropy: &mut dyn EntropySource) -> Result<Casting> {
+    let mut splits = Vec::with_capacity(6);
+    for _ in 0..6 {
+        splits.push(yarrow_stalk_line(entropy)?);
+    }
+    Ok(Casting::YarrowStalks {
+        splits: splits.try_into().unwrap(),
+    })
 }
 
-/// Generates a reading using numbers from random.org and the yarrow stalks method.
-fn yarrow_stalk_reading(randomness: RandomnessMode) -> Result<Vec<u8>> {
-    vec![0; 6]
-        .iter()
-        .map(|_| yarrow_stalk_line(randomness))
-        .collect()
+/// Returns a pseudo-random number generator initialized with the given seed. The same seed always
+/// produces the same sequence of numbers, on every platform.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
 }
 
-/// Generate a reading of the I Ching using the given reading mode and randomness mode.
+/// Generate a reading of the I Ching using the given reading method. The numbers needed to cast the
+/// lines are drawn from the given source of randomness, except for the plum blossom method, which
+/// uses the current time in the lunar calendar instead. To cast a plum blossom reading from another
+/// time or from numbers, build its casting with the [`plum_blossom`](crate::plum_blossom) module
+/// and pass it to [`reading_from_casting`].
 pub fn generate_reading(
     method: ReadingMethod,
-    randomness: RandomnessMode,
+    entropy: &mut dyn EntropySource,
     question: &str,
 ) -> Result<Reading> {
-    // Generate the throws according to the reading method.
-    let throws = match method {
-        ReadingMethod::Coin => coin_reading(randomness)?,
-        ReadingMethod::YarrowStalks => yarrow_stalk_reading(randomness)?,
+    // Cast the lines according to the reading method.
+    let casting = match method {
+        ReadingMethod::Coin => coin_reading(entropy)?,
+        ReadingMethod::YarrowStalks => yarrow_stalk_reading(entropy)?,
+        ReadingMethod::SixteenMarbles => sixteen_marbles_reading(entropy)?,
+        ReadingMethod::TwoCoin => two_coin_reading(entropy)?,
+        ReadingMethod::Dice => dice_reading(entropy)?,
+        ReadingMethod::PlumBlossom => LunarTime::now()?.casting(),
     };
+    let mut reading = reading_from_casting(casting, question)?;
+    reading.set_fallback(entropy.fallback_reason().map(str::to_string));
+    reading.set_signatures(entropy.take_signatures());
+    Ok(reading)
+}
+
+/// Builds a reading by replaying the given casting. Returns an error if the casting is not valid.
+pub fn reading_from_casting(casting: Casting, question: &str) -> Result<Reading> {
+    let throws = casting.replay()?;
+    let mut reading = reading_from_throws(&throws, question)?;
+    reading.casting = Some(casting);
+    Ok(reading)
+}
+
+/// Builds a reading from the values of the six lines, from bottom to top. Each value must be 6 (old
+/// yin), 7 (young yang), 8 (young yin), or 9 (old yang).
+pub fn reading_from_throws(throws: &[u8], question: &str) -> Result<Reading> {
+    if throws.len() != 6 {
+        bail!("expected 6 throws, got {}", throws.len());
+    }
 
     // Convert the throws into the present and future lines.
     let mut present_lines = [Line::Open; 6];
@@ -732,7 +2195,11 @@ pub fn generate_reading(
                 future_lines[i] = Line::Open;
                 changing_lines.insert(i);
             }
-            _ => bail!("bad throw: {}", throw),
+            _ => bail!(
+                "invalid value for line {}: {} (expected 6, 7, 8, or 9)",
+                i + 1,
+                throw
+            ),
         }
     }
 
@@ -745,12 +2212,17 @@ pub fn generate_reading(
         "cannot find hexagram for future lines: {:?}",
         future_lines
     ))?;
+    let throws = throws.try_into().unwrap();
     if present_lines == future_lines {
         Ok(Reading {
             question: question.to_string(),
             present: present_hex,
             future: None,
             changing_lines,
+            throws,
+            casting: None,
+            fallback: None,
+            signatures: Vec::new(),
         })
     } else {
         Ok(Reading {
@@ -758,6 +2230,10 @@ pub fn generate_reading(
             present: present_hex,
             future: Some(future_hex),
             changing_lines,
+            throws,
+            casting: None,
+            fallback: None,
+            signatures: Vec::new(),
         })
     }
 }
@@ -767,6 +2243,215 @@ mod test {
     use anyhow::Result;
 
     use super::*;
+    use crate::{
+        entropy::{SeededRandom, ThreadRandom},
+        random_org::{RandomOrg, StandInServer},
+    };
+
+    /// Returns the applicable texts of the reading with the given throws as a list of references
+    /// and whether each one is primary.
+    fn applicable_texts(throws: [u8; 6]) -> Vec<(TextReference, bool)> {
+        reading_from_throws(&throws, "")
+            .unwrap()
+            .applicable_texts()
+            .into_iter()
+            .map(|text| (text.reference, text.primary))
+            .collect()
+    }
+
+    /// Returns the reading methods that draw their numbers from the source of randomness.
+    fn drawn_methods() -> Vec<ReadingMethod> {
+        ReadingMethod::value_variants()
+            .iter()
+            .filter(|method| **method != ReadingMethod::PlumBlossom)
+            .cloned()
+            .collect()
+    }
+
+    /// Verifies that readings generated with the same seed are identical.
+    #[test]
+    fn test_seeded_readings() -> Result<()> {
+        for method in drawn_methods() {
+            let generate =
+                |seed| generate_reading(method.clone(), &mut SeededRandom::new(seed), "Question");
+            assert_eq!(generate(42)?, generate(42)?);
+            let readings: HashSet<[u8; 6]> = (0..10)
+                .map(|seed| Ok(generate(seed)?.line_values()))
+                .collect::<Result<_>>()?;
+            assert!(readings.len() > 1);
+        }
+        Ok(())
+    }
+
+    /// Verifies counting the remainders of a split of the yarrow stalks.
+    #[test]
+    fn test_yarrow_split() -> Result<()> {
+        let split = yarrow_stalk_split_with(49, 20)?;
+        assert_eq!(split.left, 29);
+        assert_eq!(split.left_remainder, 4);
+        assert_eq!(split.right_remainder, 4);
+        assert_eq!(split.groups, 10);
+        assert_eq!(split.remaining()?, 40);
+        assert!(yarrow_stalk_split_with(49, 0).is_err());
+        assert!(yarrow_stalk_split_with(49, 48).is_err());
+        Ok(())
+    }
+
+    /// Verifies that generated readings keep a casting that replays to the same lines, and that
+    /// tampered castings are rejected.
+    #[test]
+    fn test_replay_casting() -> Result<()> {
+        for method in ReadingMethod::value_variants() {
+            let reading = generate_reading(method.clone(), &mut ThreadRandom::new(), "")?;
+            let casting = reading.casting().unwrap();
+            assert_eq!(casting.replay()?, reading.line_values());
+            assert_eq!(reading_from_casting(casting.clone(), "")?, reading);
+        }
+
+        let tampered = Casting::Coin {
+            tosses: [
+                [2, 3, 4],
+                [2, 2, 2],
+                [2, 2, 2],
+                [2, 2, 2],
+                [2, 2, 2],
+                [2, 2, 2],
+            ],
+        };
+        assert!(tampered.replay().is_err());
+
+        let reading = generate_reading(ReadingMethod::YarrowStalks, &mut ThreadRandom::new(), "")?;
+        let mut casting = reading.casting().unwrap().clone();
+        if let Casting::YarrowStalks { splits } = &mut casting {
+            splits[0][1].groups += 1;
+        }
+        assert!(casting.replay().is_err());
+
+        // Out of range splits are rejected instead of overflowing.
+        for right in [0, 47, 48, 254, 255] {
+            let mut casting = reading.casting().unwrap().clone();
+            if let Casting::YarrowStalks { splits } = &mut casting {
+                splits[0][0].right = right;
+            }
+            assert!(casting.replay().is_err());
+            assert!(reading_from_casting(casting, "").is_err());
+        }
+        assert!(yarrow_stalk_split_with(2, 1).is_err());
+        assert!(yarrow_stalk_split_with(49, 255).is_err());
+        Ok(())
+    }
+
+    /// Verifies that the line values of a reading match the throws used to build it.
+    #[test]
+    fn test_line_values() -> Result<()> {
+        let throws = [6, 7, 8, 9, 7, 8];
+        let reading = reading_from_throws(&throws, "")?;
+        assert_eq!(reading.line_values(), throws);
+        assert_eq!(reading.present().number, 47);
+        assert_eq!(reading.future().unwrap().number, 60);
+        Ok(())
+    }
+
+    /// Verifies the texts chosen for each number of changing lines.
+    #[test]
+    fn test_zhu_xi_rules() {
+        use TextReference::*;
+
+        // No changing lines: 7 7 7 8 8 8 is hexagram 11.
+        assert_eq!(
+            applicable_texts([7, 7, 7, 8, 8, 8]),
+            vec![(Judgment { hexagram: 11 }, true)]
+        );
+
+        // One changing line.
+        assert_eq!(
+            applicable_texts([7, 7, 9, 8, 8, 8]),
+            vec![(
+                Line {
+                    hexagram: 11,
+                    line: 2
+                },
+                true
+            )]
+        );
+
+        // Two changing lines, the upper one is primary.
+        assert_eq!(
+            applicable_texts([9, 7, 7, 8, 6, 8]),
+            vec![
+                (
+                    Line {
+                        hexagram: 11,
+                        line: 4
+                    },
+                    true
+                ),
+                (
+                    Line {
+                        hexagram: 11,
+                        line: 0
+                    },
+                    false
+                )
+            ]
+        );
+
+        // Three changing lines: 11 changes into 2.
+        assert_eq!(
+            applicable_texts([9, 9, 9, 8, 8, 8]),
+            vec![
+                (Judgment { hexagram: 11 }, true),
+                (Judgment { hexagram: 2 }, false)
+            ]
+        );
+
+        // Four changing lines: lines 2 and 5 stay the same in hexagram 64.
+        assert_eq!(
+            applicable_texts([9, 7, 9, 6, 8, 6]),
+            vec![
+                (
+                    Line {
+                        hexagram: 64,
+                        line: 1
+                    },
+                    true
+                ),
+                (
+                    Line {
+                        hexagram: 64,
+                        line: 4
+                    },
+                    false
+                )
+            ]
+        );
+
+        // Five changing lines: only the top line stays the same in hexagram 23.
+        assert_eq!(
+            applicable_texts([9, 9, 9, 9, 9, 7]),
+            vec![(
+                Line {
+                    hexagram: 23,
+                    line: 5
+                },
+                true
+            )]
+        );
+
+        // Six changing lines: hexagrams 1 and 2 have their own statement.
+        assert_eq!(
+            applicable_texts([9; 6]),
+            vec![(AllLines { hexagram: 1 }, true)]
+        );
+        assert_eq!(
+            applicable_texts([6; 6]),
+            vec![(AllLines { hexagram: 2 }, true)]
+        );
+        assert_eq!(
+            applicable_texts([9, 9, 9, 6, 6, 6]),
+            vec![(Judgment { hexagram: 12 }, true)]
+        );
+    }
 
     /// A trait used to test an arbitrary method to generate an I Ching reading.
     trait ReadingGenerator {
@@ -774,10 +2459,14 @@ mod test {
     }
 
     /// A reading method using the coin method with true randomness.
-    struct CoinRandom {}
+    struct CoinRandom {
+        random_org: RandomOrg,
+    }
     impl ReadingGenerator for CoinRandom {
         fn generate_reading(&self) -> Result<Vec<u8>> {
-            coin_reading(RandomnessMode::Random)
+            Ok(coin_reading(&mut self.random_org.clone())?
+                .replay()?
+                .to_vec())
         }
     }
 
@@ -785,15 +2474,19 @@ mod test {
     struct CoinPseudorandom {}
     impl ReadingGenerator for CoinPseudorandom {
         fn generate_reading(&self) -> Result<Vec<u8>> {
-            coin_reading(RandomnessMode::Pseudorandom)
+            Ok(coin_reading(&mut ThreadRandom::new())?.replay()?.to_vec())
         }
     }
 
     /// A reading method using the yarrow stalks method with true randomness.
-    struct YarrowStalksRandom {}
+    struct YarrowStalksRandom {
+        random_org: RandomOrg,
+    }
     impl ReadingGenerator for YarrowStalksRandom {
         fn generate_reading(&self) -> Result<Vec<u8>> {
-            yarrow_stalk_reading(RandomnessMode::Random)
+            Ok(yarrow_stalk_reading(&mut self.random_org.clone())?
+                .replay()?
+                .to_vec())
         }
     }
 
@@ -801,7 +2494,9 @@ mod test {
     struct YarrowStalksPseudorandom {}
     impl ReadingGenerator for YarrowStalksPseudorandom {
         fn generate_reading(&self) -> Result<Vec<u8>> {
-            yarrow_stalk_reading(RandomnessMode::Pseudorandom)
+            Ok(yarrow_stalk_reading(&mut ThreadRandom::new())?
+                .replay()?
+                .to_vec())
         }
     }
 
@@ -819,7 +2514,7 @@ mod test {
                 if reading.len() != 6 {
                     bail!("reading has wrong number of lines: {}", reading.len());
                 }
-                if reading.iter().any(|&x| x < 6 || x > 9) {
+                if reading.iter().any(|x| !(6..=9).contains(x)) {
                     bail!("reading has invalid throw: {:?}", reading);
                 }
             }
@@ -827,11 +2522,15 @@ mod test {
         }
     }
 
-    /// Verifies the coin method with true randomness.
+    /// Verifies the coin method with true randomness. The numbers are drawn from a local stand-in
+    /// for random.org so that the test does not need network access.
     #[test]
     fn test_coin_random() -> Result<()> {
+        let server = StandInServer::start(1)?;
         ReadingVerifier {
-            reading_method: Box::new(CoinRandom {}),
+            reading_method: Box::new(CoinRandom {
+                random_org: server.client(),
+            }),
             num_readings: 1,
         }
         .verify_reading()
@@ -847,11 +2546,15 @@ mod test {
         .verify_reading()
     }
 
-    /// Verifies the yarrow stalks method with true randomness.
+    /// Verifies the yarrow stalks method with true randomness. The numbers are drawn from a local
+    /// stand-in for random.org so that the test does not need network access.
     #[test]
     fn test_yarrow_stalks_random() -> Result<()> {
+        let server = StandInServer::start(2)?;
         ReadingVerifier {
-            reading_method: Box::new(YarrowStalksRandom {}),
+            reading_method: Box::new(YarrowStalksRandom {
+                random_org: server.client(),
+            }),
             num_readings: 1,
         }
         .verify_reading()
@@ -884,4 +2587,132 @@ mod test {
         }
         Ok(())
     }
+
+    /// Verifies that the names and symbols of the hexagrams and trigrams match the tables.
+    #[test]
+    fn test_hexagram_names_and_symbols() -> Result<()> {
+        for hexagram in HEXAGRAM_INDEX.values() {
+            let expected = char::from_u32(0x4DC0 + hexagram.number as u32 - 1).unwrap();
+            assert_eq!(hexagram.symbol, expected);
+            assert!(!hexagram.name.is_empty());
+            assert!(!hexagram.chinese_name.is_empty());
+            assert!(!hexagram.english_names.is_empty());
+
+            let (bottom, top) = hexagram.trigrams();
+            assert!(('\u{2630}'..='\u{2637}').contains(&bottom.symbol));
+            assert!(('\u{2630}'..='\u{2637}').contains(&top.symbol));
+        }
+
+        let ji_ji = create_hexagram(63, HEXAGRAMS[62].1);
+        assert_eq!(ji_ji.name, "Ji Ji");
+        assert_eq!(ji_ji.symbol, '䷾');
+        let (bottom, top) = ji_ji.trigrams();
+        assert_eq!((bottom.name, bottom.symbol), ("Li", '☲'));
+        assert_eq!((top.name, top.symbol), ("Kan", '☵'));
+
+        // The names come from the lines, whatever the number says.
+        let qian = create_hexagram(0, HEXAGRAMS[0].1);
+        assert_eq!((qian.name, qian.symbol), ("Qian", '䷀'));
+        let qian = create_hexagram(65, [1, 1, 1, 1, 1, 2]);
+        assert_eq!((qian.name, qian.symbol), ("Qian", '䷀'));
+        Ok(())
+    }
+
+    /// Verifies that the frequency of each line value matches the documented probabilities of each
+    /// method that draws random numbers.
+    #[test]
+    fn test_line_probabilities() -> Result<()> {
+        let num_readings = 5000;
+        for method in drawn_methods() {
+            // Splitting the stalks uniformly only approximates the traditional probabilities.
+            let tolerance = match method {
+                ReadingMethod::YarrowStalks => 0.03,
+                _ => 0.01,
+            };
+            let mut entropy = SeededRandom::new(9);
+            let mut counts = [0; 4];
+            for _ in 0..num_readings {
+                let reading = generate_reading(method.clone(), &mut entropy, "")?;
+                for value in reading.line_values() {
+                    counts[value as usize - 6] += 1;
+                }
+            }
+            for (count, probability) in counts.iter().zip(method.line_probabilities()) {
+                let frequency = *count as f64 / (num_readings * 6) as f64;
+                assert!(
+                    (frequency - probability).abs() < tolerance,
+                    "{}: {:?}",
+                    method,
+                    counts
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies the methods that cast a reading from two trigrams and a changing line.
+    #[test]
+    fn test_trigram_castings() -> Result<()> {
+        // Earth over heaven, with the bottom line changing.
+        let reading = reading_from_casting(
+            Casting::Dice {
+                lower: 1,
+                upper: 8,
+                line: 1,
+            },
+            "",
+        )?;
+        assert_eq!(reading.present().number, 11);
+        assert_eq!(reading.future().unwrap().number, 46);
+        assert!(reading_from_casting(
+            Casting::Dice {
+                lower: 9,
+                upper: 1,
+                line: 1
+            },
+            ""
+        )
+        .is_err());
+        Ok(())
+    }
+
+    /// Verifies parsing trigrams from their lines, symbols, and names.
+    #[test]
+    fn test_parse_trigrams() -> Result<()> {
+        for text in ["010", "☵", "Kan", "kan", "坎", "Water", "The Abysmal"] {
+            let trigram: Trigram = text.parse()?;
+            assert_eq!(trigram.name, "Kan", "{}", text);
+        }
+        assert!("0101".parse::<Trigram>().is_err());
+        assert!("Fire and Water".parse::<Trigram>().is_err());
+        Ok(())
+    }
+
+    /// Verifies parsing hexagrams from their lines, numbers, trigrams, symbols, and names.
+    #[test]
+    fn test_parse_hexagrams() -> Result<()> {
+        assert_eq!("010110".parse::<Hexagram>()?.number, 47);
+        for text in [
+            "63",
+            "101010",
+            "䷾",
+            "☵ over ☲",
+            "Kan/Li",
+            "water OVER fire",
+            "Ji Ji",
+            "jiji",
+            "既濟",
+            "After Completion",
+        ] {
+            assert_eq!(text.parse::<Hexagram>()?.number, 63, "{}", text);
+        }
+
+        let error = "Kun".parse::<Hexagram>().unwrap_err().to_string();
+        assert!(error.contains("2, 47"), "{}", error);
+        assert!("0".parse::<Hexagram>().is_err());
+        assert!("65".parse::<Hexagram>().is_err());
+        assert!("Kan/Foo".parse::<Hexagram>().is_err());
+        assert!("foo".parse::<Hexagram>().is_err());
+        Ok(())
+    }
 }
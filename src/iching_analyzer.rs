@@ -3,12 +3,17 @@
 use anyhow::{bail, Result};
 use rand::seq::SliceRandom;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-use std::collections::VecDeque;
+use serde::{Serialize, Serializer};
 
-use crate::iching::{create_hexagram, Hexagram, HexagramLine, Trigram, HEXAGRAMS};
+use crate::{
+    distance_matrix::DISTANCE_MATRIX,
+    hexagram_bits::HexagramBits,
+    iching::{create_hexagram, seeded_rng, Hexagram, HexagramLine, Trigram, HEXAGRAMS},
+};
 
 /// The operations that can be applied to transform a hexagram.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "line", rename_all = "snake_case")]
 pub enum SearchOperation {
     /// No operation.
     NoOp,
@@ -56,7 +61,7 @@ pub enum SearchOperation {
 
 impl SearchOperation {
     /// Returns all possible search operations.
-    fn all_operations() -> Vec<SearchOperation> {
+    pub fn all_operations() -> Vec<SearchOperation> {
         vec![
             Self::InverseLine(HexagramLine::First),
             Self::InverseLine(HexagramLine::Second),
@@ -79,14 +84,14 @@ impl SearchOperation {
     }
 
     /// Applies the search operation to the given hexagram.
-    fn apply(&self, hexagram: &Hexagram) -> Hexagram {
+    pub fn apply(&self, hexagram: &Hexagram) -> Hexagram {
+        self.apply_bits(hexagram.into()).hexagram()
+    }
+
+    /// Applies the search operation to the given hexagram, stored as the bits of its lines.
+    pub fn apply_bits(&self, hexagram: HexagramBits) -> HexagramBits {
         match self {
-            Self::InverseLine(HexagramLine::First) => hexagram.inverse_line(HexagramLine::First),
-            Self::InverseLine(HexagramLine::Second) => hexagram.inverse_line(HexagramLine::Second),
-            Self::InverseLine(HexagramLine::Third) => hexagram.inverse_line(HexagramLine::Third),
-            Self::InverseLine(HexagramLine::Fourth) => hexagram.inverse_line(HexagramLine::Fourth),
-            Self::InverseLine(HexagramLine::Fifth) => hexagram.inverse_line(HexagramLine::Fifth),
-            Self::InverseLine(HexagramLine::Sixth) => hexagram.inverse_line(HexagramLine::Sixth),
+            Self::InverseLine(line) => hexagram.inverse_line(line.line_to_index()),
             Self::InverseHexagram => hexagram.inverse(),
             Self::InverseBottomTrigram => hexagram.inverse_bottom_trigram(),
             Self::InverseTopTrigram => hexagram.inverse_top_trigram(),
@@ -98,12 +103,13 @@ impl SearchOperation {
             Self::NuclearTrigrams => hexagram.use_nuclear_trigrams(),
             Self::MixTrigramsBottomFirst => hexagram.mix_trigrams_bottom_first(),
             Self::MixTrigramsTopFirst => hexagram.mix_trigrams_top_first(),
-            Self::NoOp => *hexagram,
+            Self::NoOp => hexagram,
         }
     }
 }
 
 /// The result of analyzing a hexagram.
+#[derive(Serialize)]
 pub struct HexagramAnalysis {
     /// The hexagram to analyze.
     pub hexagram: Hexagram,
@@ -123,6 +129,7 @@ pub struct HexagramAnalysis {
     pub top_nuclear_trigram: Trigram,
 
     /// The list of hexagrams that can be reached from this hexagram by applying a single operation.
+    #[serde(serialize_with = "serialize_path")]
     pub reacheable_hexagrams: Vec<(Hexagram, SearchOperation)>,
 }
 
@@ -157,7 +164,7 @@ impl HexagramAnalysis {
 
     /// Prints the hexagram analysis.
     pub fn print(&self) {
-        println!(">>>>> Analysis of hexagram {}:", self.hexagram.number);
+        println!(">>>>> Analysis of hexagram {}:", self.hexagram);
         println!();
         self.hexagram.print(None);
         println!();
@@ -187,7 +194,7 @@ impl HexagramAnalysis {
         for (hexagram, op) in &self.reacheable_hexagrams {
             println!(
                 "> Hexagram {} can be reached by applying the operation {:?}",
-                hexagram.number, op
+                hexagram, op
             );
             println!();
             hexagram.print(None);
@@ -197,7 +204,86 @@ impl HexagramAnalysis {
 }
 
 /// A path between two hexagrams, containing the hexagrams and operations to transform them.
-type Path = Vec<(Hexagram, SearchOperation)>;
+pub type Path = Vec<(Hexagram, SearchOperation)>;
+
+/// A single step in a path, used to serialize each step as an object instead of a tuple.
+#[derive(Serialize)]
+struct PathStep<'a> {
+    /// The hexagram reached in this step.
+    hexagram: &'a Hexagram,
+
+    /// The operation applied to the previous hexagram to reach this one.
+    operation: &'a SearchOperation,
+}
+
+/// Serializes a path as a list of steps.
+fn serialize_path<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(path.iter().map(|(hexagram, operation)| PathStep {
+        hexagram,
+        operation,
+    }))
+}
+
+/// Wrapper to serialize a path as a list of steps inside other collections.
+struct SerializablePath<'a>(&'a Path);
+
+impl Serialize for SerializablePath<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_path(self.0, serializer)
+    }
+}
+
+/// Serializes a list of paths as a list of lists of steps.
+fn serialize_paths<S: Serializer>(paths: &[Path], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(paths.iter().map(SerializablePath))
+}
+
+/// Serializes the paths between each pair of hexagrams in a sequence.
+fn serialize_sequence_paths<S: Serializer>(
+    paths: &[Vec<Path>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        paths
+            .iter()
+            .map(|paths| paths.iter().map(SerializablePath).collect::<Vec<_>>()),
+    )
+}
+
+/// The shortest paths found between two hexagrams.
+#[derive(Clone, Debug, Serialize)]
+pub struct ShortestPaths {
+    /// The number of the hexagram from which the search started.
+    pub start: usize,
+
+    /// The number of the hexagram to reach.
+    pub end: usize,
+
+    /// The number of shortest paths found between both hexagrams, which may be more than the
+    /// paths listed.
+    pub num_paths: u64,
+
+    /// The shortest paths found between both hexagrams.
+    #[serde(serialize_with = "serialize_paths")]
+    pub paths: Vec<Path>,
+}
+
+impl ShortestPaths {
+    /// Prints the shortest paths.
+    pub fn print(&self) {
+        println!(
+            ">>>>> Shortest path search from {} to {}",
+            self.start, self.end
+        );
+        println!();
+        println!(">>> Shortest path search found {} path(s)", self.num_paths);
+        if (self.paths.len() as u64) < self.num_paths {
+            println!(">>> Showing the first {} path(s)", self.paths.len());
+        }
+        println!();
+        print_shortest_path(self.start, self.end, &self.paths)
+    }
+}
 
 /// Prints the shortest path between two hexagrams.
 pub fn print_shortest_path(start: usize, end: usize, paths: &[Path]) {
@@ -265,90 +351,342 @@ impl HexagramSearcher {
         })
     }
 
-    /// Returns only the paths with the least line changes.
-    fn find_least_lines_changed(paths: &[Path]) -> Vec<Path> {
-        // Find the minimum number of lines for all the paths.
-        let mut min_lines_changed = u64::MAX;
-        for path in paths {
-            let line_changes = count_line_changes(path);
-            if min_lines_changed == u64::MAX || line_changes < min_lines_changed {
-                min_lines_changed = line_changes;
-            }
-        }
-
-        // Then, return all the paths with the minimum number of lines.
-        let mut out = vec![];
-        for path in paths {
-            let line_changes = count_line_changes(path);
-            if line_changes == min_lines_changed {
-                out.push(path.clone());
-            }
-        }
-        out
+    /// Searches the graph of the shortest paths between the initial and final hexagrams. If `all`
+    /// is false, the graph only keeps the shortest paths with the least line changes.
+    pub fn search(&self, all: bool) -> ShortestPathGraph {
+        ShortestPathGraph::new(
+            HexagramBits::from(&self.start_hexagram),
+            HexagramBits::from(&self.end_hexagram),
+            all,
+        )
     }
 
-    /// Returns all the shortest paths between the initial and final hexagrams.
+    /// Returns all the shortest paths between the initial and final hexagrams, or only the ones
+    /// with the least line changes if `all` is false.
     pub fn find_shortest_paths(&self, all: bool) -> Vec<Path> {
-        // Create a queue of paths to search to perform a breadth-first search.
-        let mut queue: VecDeque<Vec<(Hexagram, SearchOperation)>> = VecDeque::new();
-        queue.push_back(vec![(self.start_hexagram, SearchOperation::NoOp)]);
+        self.search(all).paths().collect()
+    }
+
+    /// Counts the shortest paths between the initial and final hexagrams, or only the ones with
+    /// the least line changes if `all` is false, without building them.
+    pub fn count_shortest_paths(&self, all: bool) -> u64 {
+        self.search(all).num_paths()
+    }
+}
+
+/// The shortest paths between two hexagrams, stored as a directed acyclic graph over the 64
+/// hexagrams. Each hexagram in the graph keeps the operations that lead from it to the next
+/// hexagrams of the paths, so the paths can be counted and enumerated without storing each one.
+#[derive(Clone, Debug)]
+pub struct ShortestPathGraph {
+    /// The hexagram where the paths start.
+    start: HexagramBits,
+
+    /// The hexagram where the paths end.
+    end: HexagramBits,
+
+    /// The number of operations of each path.
+    num_ops: usize,
+
+    /// The number of line changes of the shortest paths with the least line changes.
+    line_changes: u64,
+
+    /// The number of paths in the graph.
+    num_paths: u64,
+
+    /// The steps that continue a path from each hexagram towards the end hexagram, in the order of
+    /// [`SearchOperation::all_operations`], indexed by the bits of the hexagram.
+    next_steps: Vec<Vec<(HexagramBits, SearchOperation)>>,
+}
+
+impl ShortestPathGraph {
+    /// Builds the graph of the shortest paths from the start hexagram to the end hexagram with a
+    /// breadth-first search that visits each hexagram once, layer by layer. If `all` is false, the
+    /// graph only keeps the shortest paths with the least line changes.
+    pub fn new(start: HexagramBits, end: HexagramBits, all: bool) -> Self {
         let ops = SearchOperation::all_operations();
+        let index = |hexagram: HexagramBits| hexagram.bits() as usize;
+
+        // Visit the hexagrams in layers of equal distance to the start hexagram until the end
+        // hexagram is reached. Each hexagram keeps all the steps that reach it from the previous
+        // layer.
+        let mut distances = [usize::MAX; 64];
+        let mut parents: Vec<Vec<(HexagramBits, SearchOperation)>> = vec![vec![]; 64];
+        let mut layers = vec![vec![start]];
+        distances[index(start)] = 0;
+        while distances[index(end)] == usize::MAX {
+            let mut layer = vec![];
+            for hexagram in layers.last().unwrap() {
+                let distance = distances[index(*hexagram)] + 1;
+                for operation in &ops {
+                    let next = operation.apply_bits(*hexagram);
+                    if distances[index(next)] == usize::MAX {
+                        distances[index(next)] = distance;
+                        layer.push(next);
+                    }
+                    if distances[index(next)] == distance {
+                        parents[index(next)].push((*hexagram, *operation));
+                    }
+                }
+            }
+            layers.push(layer);
+        }
 
-        // Store a list of all the shortest paths. Then search until the queue is empty, or we find
-        // all the shortest paths.
-        let mut shortest_paths: Vec<Path> = vec![];
-        while !queue.is_empty() {
-            // Take the next path from the queue. Break out of the loop if a shorter path has been
-            // found.
-            let path = queue.pop_front().unwrap();
-            if !shortest_paths.is_empty() && path.len() >= shortest_paths[0].len() {
-                break;
+        // Compute the least line changes needed to reach each hexagram in a shortest path, and
+        // keep only the steps that achieve them if asked to.
+        let mut line_changes = [0; 64];
+        for layer in &layers[1..] {
+            for hexagram in layer {
+                let changes = |(parent, _): &(HexagramBits, SearchOperation)| {
+                    line_changes[index(*parent)] + parent.num_line_changes(*hexagram) as u64
+                };
+                let least = parents[index(*hexagram)].iter().map(changes).min().unwrap();
+                if !all {
+                    parents[index(*hexagram)].retain(|step| changes(step) == least);
+                }
+                line_changes[index(*hexagram)] = least;
             }
+        }
 
-            // Try each operation on the current hexagram.
-            let (current_hexagram, _) = path.last().unwrap();
-            for operation in &ops {
-                // Create a new hexagram with the given operation. Ignore it if it's already in the
-                // path.
-                let new_hexagram = operation.apply(current_hexagram);
-                if path.iter().any(|(h, _)| h == &new_hexagram) {
-                    continue;
+        // Walk back from the end hexagram to keep only the hexagrams that lead to it.
+        let mut in_graph = [false; 64];
+        in_graph[index(end)] = true;
+        for layer in layers.iter().rev() {
+            for hexagram in layer {
+                if in_graph[index(*hexagram)] {
+                    for (parent, _) in &parents[index(*hexagram)] {
+                        in_graph[index(*parent)] = true;
+                    }
                 }
+            }
+        }
 
-                // Create the new path and return it if it's the final hexagram. Otherwise, add the
-                // new path to the queue.
-                let mut new_path = path.clone();
-                new_path.push((new_hexagram, operation.clone()));
-                if new_hexagram == self.end_hexagram {
-                    shortest_paths.push(new_path.clone());
-                } else {
-                    queue.push_back(new_path);
+        // Count the paths reaching each hexagram, and turn the steps around so that the paths can
+        // be followed from the start hexagram.
+        let mut num_paths = [0; 64];
+        num_paths[index(start)] = 1;
+        let mut next_steps = vec![vec![]; 64];
+        for layer in &layers[1..] {
+            for hexagram in layer.iter().filter(|h| in_graph[index(**h)]) {
+                for (parent, operation) in &parents[index(*hexagram)] {
+                    num_paths[index(*hexagram)] += num_paths[index(*parent)];
+                    next_steps[index(*parent)].push((*hexagram, *operation));
                 }
             }
         }
+        let position = |operation: &SearchOperation| ops.iter().position(|op| op == operation);
+        for steps in &mut next_steps {
+            steps.sort_by_key(|(_, operation)| position(operation));
+        }
+
+        Self {
+            start,
+            end,
+            num_ops: layers.len() - 1,
+            line_changes: line_changes[index(end)],
+            num_paths: num_paths[index(end)],
+            next_steps,
+        }
+    }
 
-        // Return the shortest paths. Either return all or only the ones with the least number of
-        // lines changed.
-        if all {
-            shortest_paths
-        } else {
-            Self::find_least_lines_changed(&shortest_paths)
+    /// Returns the number of operations of each path.
+    pub fn num_ops(&self) -> usize {
+        self.num_ops
+    }
+
+    /// Returns the number of line changes of the shortest paths with the least line changes.
+    pub fn line_changes(&self) -> u64 {
+        self.line_changes
+    }
+
+    /// Returns the number of paths in the graph.
+    pub fn num_paths(&self) -> u64 {
+        self.num_paths
+    }
+
+    /// Returns an iterator that builds the paths in the graph one at a time, in the order of the
+    /// operations of [`SearchOperation::all_operations`].
+    pub fn paths(&self) -> ShortestPathIter<'_> {
+        ShortestPathIter {
+            graph: self,
+            path: vec![(self.start, SearchOperation::NoOp)],
+            next_step: vec![0],
         }
     }
 }
 
+/// An iterator over the paths of a [`ShortestPathGraph`], which follows the graph depth first.
+#[derive(Clone, Debug)]
+pub struct ShortestPathIter<'a> {
+    /// The graph of the paths.
+    graph: &'a ShortestPathGraph,
+
+    /// The path being followed.
+    path: Vec<(HexagramBits, SearchOperation)>,
+
+    /// The position of the next step to follow from each hexagram of the path.
+    next_step: Vec<usize>,
+}
+
+impl Iterator for ShortestPathIter<'_> {
+    type Item = Path;
+
+    fn next(&mut self) -> Option<Path> {
+        // Every hexagram in the graph leads to the end hexagram, so each branch yields a path.
+        while let Some((hexagram, _)) = self.path.last().copied() {
+            if hexagram == self.graph.end {
+                let path = self
+                    .path
+                    .iter()
+                    .map(|(hexagram, operation)| (hexagram.hexagram(), *operation))
+                    .collect();
+                self.path.pop();
+                self.next_step.pop();
+                return Some(path);
+            }
+
+            let steps = &self.graph.next_steps[hexagram.bits() as usize];
+            let next_step = self.next_step.last_mut().unwrap();
+            match steps.get(*next_step) {
+                Some(step) => {
+                    *next_step += 1;
+                    self.path.push(*step);
+                    self.next_step.push(0);
+                }
+                None => {
+                    self.path.pop();
+                    self.next_step.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
 /// King Wen's sequence is the sequence of hexagrams as they appear in the I Ching.
 pub fn king_wen() -> Vec<usize> {
     (1..=64).collect()
 }
 
+/// The relation between the two hexagrams of a pair in a sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PairRelation {
+    /// The second hexagram is the first one turned upside down.
+    Reverse,
+
+    /// The second hexagram is the first one with all its lines inverted. King Wen's sequence uses
+    /// this relation for the hexagrams that are the same upside down.
+    Inverse,
+
+    /// The second hexagram is both the first one turned upside down and the first one with all its
+    /// lines inverted, as with Tai and Pi.
+    ReverseAndInverse,
+
+    /// The hexagrams are not related by reversal or inversion.
+    Unrelated,
+}
+
+impl PairRelation {
+    /// Returns the relation between the given hexagrams.
+    pub fn classify(first: &Hexagram, second: &Hexagram) -> Self {
+        let reverse = first.reverse() == *second && first != second;
+        let inverse = first.inverse() == *second;
+        match (reverse, inverse) {
+            (true, true) => PairRelation::ReverseAndInverse,
+            (true, false) => PairRelation::Reverse,
+            (false, true) => PairRelation::Inverse,
+            (false, false) => PairRelation::Unrelated,
+        }
+    }
+}
+
+/// The analysis of a sequence as a list of pairs: the hexagrams at positions 1 and 2, 3 and 4, and
+/// so on. If the sequence has an odd length, its last hexagram belongs to no pair.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct PairAnalysis {
+    /// The relation between the hexagrams of each pair.
+    pub relations: Vec<PairRelation>,
+
+    /// The number of pairs related by reversal only.
+    pub reverse_pairs: usize,
+
+    /// The number of pairs related by inversion only.
+    pub inverse_pairs: usize,
+
+    /// The number of pairs related by both reversal and inversion.
+    pub reverse_and_inverse_pairs: usize,
+
+    /// The number of pairs whose hexagrams are not related by reversal or inversion.
+    pub unrelated_pairs: usize,
+
+    /// The number of pairs whose hexagrams differ by 0 to 6 lines, indexed by the number of lines.
+    pub within_pairs: [usize; 7],
+
+    /// The number of consecutive pairs, from the second hexagram of one pair to the first hexagram
+    /// of the next, that differ by 0 to 6 lines, indexed by the number of lines. In King Wen's
+    /// sequence, no consecutive pairs differ by 5 lines.
+    pub between_pairs: [usize; 7],
+}
+
+impl PairAnalysis {
+    /// Analyzes the pairs of the given sequence of hexagrams.
+    pub fn new(sequence: &[usize]) -> Result<Self> {
+        let hexagrams = sequence
+            .iter()
+            .map(|number| match u8::try_from(*number) {
+                Ok(number) => Hexagram::from_number(number),
+                Err(_) => bail!("invalid hexagram number: {}", number),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let pairs: Vec<&[Hexagram]> = hexagrams.chunks_exact(2).collect();
+
+        let mut analysis = Self::default();
+        for pair in &pairs {
+            let relation = PairRelation::classify(&pair[0], &pair[1]);
+            match relation {
+                PairRelation::Reverse => analysis.reverse_pairs += 1,
+                PairRelation::Inverse => analysis.inverse_pairs += 1,
+                PairRelation::ReverseAndInverse => analysis.reverse_and_inverse_pairs += 1,
+                PairRelation::Unrelated => analysis.unrelated_pairs += 1,
+            }
+            analysis.relations.push(relation);
+            analysis.within_pairs[pair[0].num_line_changes(&pair[1])] += 1;
+        }
+        for window in pairs.windows(2) {
+            analysis.between_pairs[window[0][1].num_line_changes(&window[1][0])] += 1;
+        }
+        Ok(analysis)
+    }
+
+    /// Prints the number of pairs of each kind and the histograms of line changes.
+    fn print(&self) {
+        println!(
+            ">>> Pairs: {} reverse, {} inverse, {} reverse and inverse, {} unrelated",
+            self.reverse_pairs,
+            self.inverse_pairs,
+            self.reverse_and_inverse_pairs,
+            self.unrelated_pairs
+        );
+        println!(
+            ">>> Pairs differing by 0 to 6 lines: {:?}",
+            self.within_pairs
+        );
+        println!(
+            ">>> Consecutive pairs differing by 0 to 6 lines: {:?}",
+            self.between_pairs
+        );
+    }
+}
+
 /// The result of performing a sequence analysis.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct SequenceAnalysis {
     /// The sequence of hexagrams.
     pub sequence: Vec<usize>,
 
     /// The shortest paths between each pair of hexagrams.
+    #[serde(serialize_with = "serialize_sequence_paths")]
     pub shortest_paths: Vec<Vec<Path>>,
 
     /// The total number of operations between the initial and final hexagrams in the sequence.
@@ -359,6 +697,170 @@ pub struct SequenceAnalysis {
 
     /// The total number of paths from the initial to the final hexagram.
     pub total_paths: u128,
+
+    /// The analysis of the sequence as a list of pairs.
+    pub pairs: PairAnalysis,
+}
+
+/// A comparison between two sequence analyses.
+#[derive(Clone, Debug, Serialize)]
+pub struct SequenceComparison {
+    /// The analysis used as the reference.
+    pub reference: SequenceAnalysis,
+
+    /// The analysis compared against the reference.
+    pub other: SequenceAnalysis,
+}
+
+impl SequenceComparison {
+    /// Prints the comparison.
+    pub fn print(&self) {
+        self.reference.print_comparison(&self.other);
+    }
+}
+
+/// A side-by-side comparison of the analyses of several sequences.
+#[derive(Clone, Debug, Serialize)]
+pub struct SequencesComparison {
+    /// The names of the sequences, such as the name of a traditional ordering or a file path.
+    pub names: Vec<String>,
+
+    /// The analysis of each sequence, in the same order as the names.
+    pub analyses: Vec<SequenceAnalysis>,
+}
+
+impl SequencesComparison {
+    /// Analyzes each of the given sequences, given along with their names.
+    pub fn new(sequences: Vec<(String, Vec<usize>)>) -> Result<Self> {
+        let (names, sequences): (Vec<_>, Vec<_>) = sequences.into_iter().unzip();
+        let analyses = sequences
+            .into_iter()
+            .map(SequenceAnalysis::new)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { names, analyses })
+    }
+
+    /// Prints the totals of each sequence as a table, followed by the transitions whose number of
+    /// operations or line changes differs between the sequences.
+    pub fn print(&self) {
+        let width = self
+            .names
+            .iter()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Sequence".len());
+
+        println!(">>>>> Comparison of sequences of hexagrams");
+        println!();
+        println!(
+            "{:width$}  {:>10}  {:>12}  {:>8}  {:>20}",
+            "Sequence", "Operations", "Line changes", "Lines/op", "Paths"
+        );
+        for (name, analysis) in self.names.iter().zip(&self.analyses) {
+            println!(
+                "{:width$}  {:>10}  {:>12}  {:>8.3}  {:>20}",
+                name,
+                analysis.total_ops,
+                analysis.total_line_changes,
+                analysis.total_line_changes as f32 / analysis.total_ops as f32,
+                analysis.total_paths
+            );
+        }
+        println!();
+
+        println!(
+            "{:width$}  {:>7}  {:>7}  {:>4}  {:>9}  Consecutive pairs differing by 0 to 6 lines",
+            "Sequence", "Reverse", "Inverse", "Both", "Unrelated"
+        );
+        for (name, analysis) in self.names.iter().zip(&self.analyses) {
+            let pairs = &analysis.pairs;
+            println!(
+                "{:width$}  {:>7}  {:>7}  {:>4}  {:>9}  {:?}",
+                name,
+                pairs.reverse_pairs,
+                pairs.inverse_pairs,
+                pairs.reverse_and_inverse_pairs,
+                pairs.unrelated_pairs,
+                pairs.between_pairs
+            );
+        }
+        println!();
+
+        // Each cell shows the pair of hexagrams, the operations, and the line changes.
+        let costs: Vec<Vec<(u64, u64)>> = self
+            .analyses
+            .iter()
+            .map(SequenceAnalysis::transition_costs)
+            .collect();
+        let num_transitions = costs.iter().map(Vec::len).max().unwrap_or(0);
+        let differing: Vec<usize> = (0..num_transitions)
+            .filter(|&i| {
+                let first = costs[0].get(i);
+                costs.iter().any(|costs| costs.get(i) != first)
+            })
+            .collect();
+        if differing.is_empty() {
+            println!(">>> All transitions have the same number of operations and line changes");
+            return;
+        }
+
+        println!(
+            ">>> Transitions that differ, as hexagrams: operations (line changes) [{} of {}]:",
+            differing.len(),
+            num_transitions
+        );
+        println!();
+        let cells: Vec<Vec<String>> = self
+            .analyses
+            .iter()
+            .zip(&costs)
+            .map(|(analysis, costs)| {
+                differing
+                    .iter()
+                    .map(|&i| match costs.get(i) {
+                        Some((ops, line_changes)) => format!(
+                            "{}-{}: {} ({})",
+                            analysis.sequence[i],
+                            analysis.sequence[i + 1],
+                            ops,
+                            line_changes
+                        ),
+                        None => "-".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = self
+            .names
+            .iter()
+            .zip(&cells)
+            .map(|(name, cells)| {
+                cells
+                    .iter()
+                    .map(String::len)
+                    .chain(std::iter::once(name.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let header: Vec<String> = self
+            .names
+            .iter()
+            .zip(&widths)
+            .map(|(name, width)| format!("{:width$}", name))
+            .collect();
+        println!("{:>10}  {}", "Transition", header.join("  ").trim_end());
+        for (row, i) in differing.iter().enumerate() {
+            let line: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cells, width)| format!("{:width$}", cells[row]))
+                .collect();
+            println!("{:>10}  {}", i + 1, line.join("  ").trim_end());
+        }
+    }
 }
 
 impl SequenceAnalysis {
@@ -372,6 +874,7 @@ impl SequenceAnalysis {
             self.total_line_changes as f32 / self.total_ops as f32
         );
         println!(">>> Total paths: {}", self.total_paths);
+        self.pairs.print();
         println!();
     }
 
@@ -402,14 +905,26 @@ impl SequenceAnalysis {
         other.print_info();
     }
 
+    /// Returns the number of operations and of line changes of each transition between two
+    /// consecutive hexagrams in the sequence.
+    pub fn transition_costs(&self) -> Vec<(u64, u64)> {
+        self.shortest_paths
+            .iter()
+            .map(|paths| ((paths[0].len() - 1) as u64, count_line_changes(&paths[0])))
+            .collect()
+    }
+
     /// Produces the analysis of the sequence of hexagrams.
     pub fn new(sequence: Vec<usize>) -> Result<Self> {
-        // Find the shortest paths between each pair of hexagrams.
+        if sequence.len() < 2 {
+            bail!("the sequence to analyze must contain at least two hexagrams");
+        }
+
+        // Look up the shortest paths between each pair of hexagrams.
         let mut shortest_paths = vec![];
         for i in 1..sequence.len() {
-            let searcher = HexagramSearcher::new(sequence[i - 1], sequence[i])?;
-            let paths = searcher.find_shortest_paths(false);
-            shortest_paths.push(paths);
+            let paths = DISTANCE_MATRIX.shortest_paths(sequence[i - 1], sequence[i])?;
+            shortest_paths.push(paths.to_vec());
         }
 
         // Compute the other values from the shortest paths.
@@ -425,8 +940,10 @@ impl SequenceAnalysis {
             .iter()
             .map(|paths| paths.len() as u128)
             .product();
+        let pairs = PairAnalysis::new(&sequence)?;
 
         Ok(Self {
+            pairs,
             sequence,
             shortest_paths,
             total_ops,
@@ -436,13 +953,29 @@ impl SequenceAnalysis {
     }
 }
 
-/// Finds the best random shuffling of the King Wen's sequence by the number of operations.
-pub fn find_min_random_sequence(num_sequences: usize) -> Result<SequenceAnalysis> {
+/// Finds the best random shuffling of the given sequence by the number of operations. If a seed is
+/// given, each shuffle uses its own stream of a generator initialized with that seed, so the result
+/// does not depend on how the shuffles are scheduled across threads.
+pub fn find_min_random_sequence(
+    sequence: &[usize],
+    num_sequences: usize,
+    seed: Option<u64>,
+) -> Result<SequenceAnalysis> {
+    if num_sequences == 0 {
+        bail!("the number of random sequences must be positive");
+    }
     Ok((0..num_sequences)
         .into_par_iter()
-        .map(|_| {
-            let mut random_sequence = king_wen();
-            random_sequence.shuffle(&mut rand::thread_rng());
+        .map(|i| {
+            let mut random_sequence = sequence.to_vec();
+            match seed {
+                Some(seed) => {
+                    let mut rng = seeded_rng(seed);
+                    rng.set_stream(i as u64);
+                    random_sequence.shuffle(&mut rng);
+                }
+                None => random_sequence.shuffle(&mut rand::thread_rng()),
+            }
             SequenceAnalysis::new(random_sequence)
         })
         .collect::<Result<Vec<_>>>()?
@@ -459,7 +992,10 @@ mod test {
         iching_analyzer::SearchOperation,
     };
 
-    use super::HexagramSearcher;
+    use super::{
+        count_line_changes, find_min_random_sequence, king_wen, HexagramSearcher, PairAnalysis,
+        PairRelation, Path, SequenceAnalysis, SequencesComparison,
+    };
 
     #[test]
     fn test_find_path() {
@@ -477,4 +1013,105 @@ mod test {
         let path = searcher.find_shortest_paths(false);
         assert_eq!(path, expected_path);
     }
+
+    /// Verifies that the graph of the shortest paths counts as many paths as it enumerates, keeps
+    /// only the ones with the least line changes unless asked for all, and finds a single empty
+    /// path from a hexagram to itself.
+    #[test]
+    fn test_shortest_path_graph() {
+        for (start, end) in [(1, 2), (3, 50), (11, 64), (1, 64)] {
+            let searcher = HexagramSearcher::new(start, end).unwrap();
+            let all = searcher.search(true);
+            let least = searcher.search(false);
+            let all_paths: Vec<Path> = all.paths().collect();
+            let least_paths: Vec<Path> = least.paths().collect();
+            assert_eq!(all.num_paths(), all_paths.len() as u64);
+            assert_eq!(least.num_paths(), least_paths.len() as u64);
+            assert_eq!(searcher.count_shortest_paths(true), all.num_paths());
+            assert_eq!(all.line_changes(), least.line_changes());
+
+            for path in &all_paths {
+                assert_eq!(path.len(), all.num_ops() + 1);
+                assert_eq!(path[0].0.number as usize, start);
+                assert_eq!(path.last().unwrap().0.number as usize, end);
+                for step in path.windows(2) {
+                    assert_eq!(step[1].1.apply(&step[0].0), step[1].0);
+                }
+            }
+            let expected: Vec<&Path> = all_paths
+                .iter()
+                .filter(|path| count_line_changes(path) == least.line_changes())
+                .collect();
+            assert_eq!(least_paths.iter().collect::<Vec<_>>(), expected);
+        }
+
+        let graph = HexagramSearcher::new(7, 7).unwrap().search(true);
+        assert_eq!(graph.num_ops(), 0);
+        assert_eq!(graph.num_paths(), 1);
+        assert_eq!(
+            graph.paths().collect::<Vec<_>>(),
+            vec![vec![(
+                create_hexagram(7, HEXAGRAMS[6].1),
+                SearchOperation::NoOp
+            )]]
+        );
+    }
+
+    #[test]
+    fn test_seeded_random_sequence() {
+        let first = find_min_random_sequence(&king_wen(), 2, Some(7)).unwrap();
+        let second = find_min_random_sequence(&king_wen(), 2, Some(7)).unwrap();
+        assert_eq!(first.sequence, second.sequence);
+        assert_ne!(first.sequence, king_wen());
+        assert!(find_min_random_sequence(&king_wen(), 0, Some(7)).is_err());
+    }
+
+    /// Verifies that comparing several sequences keeps their names and analyses in order.
+    #[test]
+    fn test_compare_sequences() {
+        let comparison = SequencesComparison::new(vec![
+            ("first".to_string(), vec![1, 2, 3]),
+            ("second".to_string(), vec![1, 2]),
+        ])
+        .unwrap();
+        assert_eq!(comparison.names, ["first", "second"]);
+        assert_eq!(comparison.analyses[0].sequence, [1, 2, 3]);
+        assert_eq!(comparison.analyses[1].sequence, [1, 2]);
+
+        let costs = comparison.analyses[0].transition_costs();
+        assert_eq!(costs.len(), 2);
+        assert_eq!(costs[0], (1, 6));
+        assert_eq!(
+            costs.iter().map(|(ops, _)| ops).sum::<u64>(),
+            SequenceAnalysis::new(vec![1, 2, 3]).unwrap().total_ops
+        );
+    }
+
+    /// Verifies the pair structure of King Wen's sequence: every pair is related by reversal or
+    /// inversion, and no consecutive pairs differ by 5 lines.
+    #[test]
+    fn test_king_wen_pairs() {
+        let pairs = PairAnalysis::new(&king_wen()).unwrap();
+        assert_eq!(pairs.relations.len(), 32);
+        assert_eq!(pairs.relations[0], PairRelation::Inverse);
+        assert_eq!(pairs.relations[1], PairRelation::Reverse);
+        assert_eq!(pairs.relations[5], PairRelation::ReverseAndInverse);
+        assert_eq!(pairs.reverse_pairs, 24);
+        assert_eq!(pairs.inverse_pairs, 4);
+        assert_eq!(pairs.reverse_and_inverse_pairs, 4);
+        assert_eq!(pairs.unrelated_pairs, 0);
+        assert_eq!(pairs.within_pairs.iter().sum::<usize>(), 32);
+        assert_eq!(pairs.between_pairs.iter().sum::<usize>(), 31);
+        assert_eq!(pairs.between_pairs[5], 0);
+
+        // An odd hexagram at the end belongs to no pair.
+        let pairs = PairAnalysis::new(&[1, 3, 2]).unwrap();
+        assert_eq!(pairs.relations, [PairRelation::Unrelated]);
+        assert_eq!(pairs.between_pairs.iter().sum::<usize>(), 0);
+
+        // Numbers out of range are rejected instead of truncated.
+        assert!(PairAnalysis::new(&[257, 2]).is_err());
+        assert!(SequenceAnalysis::new(vec![1]).is_err());
+        assert!(SequenceAnalysis::new(vec![]).is_err());
+    }
 }
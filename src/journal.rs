@@ -0,0 +1,384 @@
+//! Module containing the journal used to persist readings.
+//!
+//! The journal is a file in which each line is a JSON object describing a reading. This makes it
+//! trivial to append new readings and to inspect or back up the journal with standard tools.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    iching::{
+        reading_from_casting, reading_from_throws, Casting, RandomnessMode, Reading, ReadingMethod,
+    },
+    random_org::SignedRandom,
+    text_pack::TextPack,
+};
+
+/// The environment variable used to override the default location of the journal.
+pub const JOURNAL_ENV_VAR: &str = "ICHING_JOURNAL";
+
+/// A reading stored in the journal.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JournalEntry {
+    /// The unique identifier of the entry. Identifiers are assigned sequentially starting at 1.
+    pub id: u64,
+
+    /// The time at which the reading was performed.
+    pub timestamp: DateTime<Utc>,
+
+    /// The question asked of the I Ching.
+    pub question: String,
+
+    /// The method used to generate the reading, if known. Readings cast by hand record the method
+    /// only if it was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<ReadingMethod>,
+
+    /// The method used to generate random numbers.
+    pub randomness: RandomnessMode,
+
+    /// The value of each line, from bottom to top.
+    pub throws: Vec<u8>,
+
+    /// The number of the present hexagram.
+    pub present: u8,
+
+    /// The number of the future hexagram, if any.
+    pub future: Option<u8>,
+
+    /// The zero-based indices of the changing lines, sorted in ascending order.
+    pub changing_lines: Vec<usize>,
+
+    /// The details of how each line was cast, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub casting: Option<Casting>,
+
+    /// The reason why a fallback source of randomness was used to cast the reading, if it was.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+
+    /// The batches of signed numbers from which the reading was cast, if random.org signed them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<SignedRandom>,
+}
+
+impl JournalEntry {
+    /// Rebuilds the reading stored in this entry. If the casting was recorded, the reading is
+    /// rebuilt by replaying it.
+    pub fn reading(&self) -> Result<Reading> {
+        let mut reading = match &self.casting {
+            Some(casting) => reading_from_casting(casting.clone(), &self.question)?,
+            None => reading_from_throws(&self.throws, &self.question)?,
+        };
+        reading.set_fallback(self.fallback.clone());
+        reading.set_signatures(self.signatures.clone());
+        Ok(reading)
+    }
+
+    /// Returns whether the given hexagram appears in the reading, either as the present or the
+    /// future hexagram.
+    pub fn contains_hexagram(&self, number: u8) -> bool {
+        self.present == number || self.future == Some(number)
+    }
+
+    /// Prints the entry along with the full reading, using the texts from the given text pack.
+    pub fn print_with_texts(&self, texts: &TextPack) -> Result<()> {
+        println!("Reading #{}", self.id);
+        println!(
+            "Date: {}",
+            self.timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+        );
+        match &self.method {
+            Some(method) => println!("Method: {} ({})", method, self.randomness),
+            None => println!("Method: unknown ({})", self.randomness),
+        }
+        println!();
+        self.reading()?.print_with_texts(texts);
+        Ok(())
+    }
+
+    /// Prints a one-line summary of the entry.
+    pub fn print_summary(&self) {
+        let hexagrams = match self.future {
+            Some(future) => format!("{} -> {}", self.present, future),
+            None => self.present.to_string(),
+        };
+        println!(
+            "{:>5}  {}  {:<8}  {}",
+            self.id,
+            self.timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            hexagrams,
+            self.question
+        );
+    }
+}
+
+/// The criteria used to search the journal. Empty criteria match every entry.
+#[derive(Clone, Debug, Default)]
+pub struct JournalQuery {
+    /// Only match readings in which this hexagram is the present or future hexagram.
+    pub hexagram: Option<u8>,
+
+    /// Only match readings whose question contains this text, ignoring case.
+    pub question: Option<String>,
+}
+
+impl JournalQuery {
+    /// Returns whether the entry matches the query.
+    pub fn matches(&self, entry: &JournalEntry) -> bool {
+        if let Some(number) = self.hexagram {
+            if !entry.contains_hexagram(number) {
+                return false;
+            }
+        }
+        if let Some(question) = &self.question {
+            if !entry
+                .question
+                .to_lowercase()
+                .contains(&question.to_lowercase())
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A journal of readings stored in a file.
+pub struct Journal {
+    /// The path to the file containing the journal.
+    path: PathBuf,
+}
+
+impl Journal {
+    /// Opens the journal stored at the given path. The file is created when the first reading is
+    /// recorded.
+    pub fn open(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Returns the default location of the journal. The location can be overridden with the
+    /// `ICHING_JOURNAL` environment variable. Otherwise, the journal is stored in the `.iching`
+    /// directory inside the home directory of the user.
+    pub fn default_path() -> PathBuf {
+        if let Some(path) = env::var_os(JOURNAL_ENV_VAR) {
+            return PathBuf::from(path);
+        }
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        home.join(".iching").join("journal.jsonl")
+    }
+
+    /// Returns the path to the file containing the journal.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns all the entries in the journal, in the order in which they were recorded.
+    pub fn entries(&self) -> Result<Vec<JournalEntry>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+        let contents = fs::read_to_string(&self.path)
+            .with_context(|| format!("cannot read journal {}", self.path.display()))?;
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("malformed entry at {}:{}", self.path.display(), i + 1)
+                })
+            })
+            .collect()
+    }
+
+    /// Records the reading in the journal and returns the new entry.
+    pub fn record(
+        &self,
+        reading: &Reading,
+        method: Option<ReadingMethod>,
+        randomness: RandomnessMode,
+    ) -> Result<JournalEntry> {
+        let id = self.entries()?.last().map_or(1, |entry| entry.id + 1);
+        let mut changing_lines: Vec<usize> = reading.changing_lines().iter().copied().collect();
+        changing_lines.sort();
+        let entry = JournalEntry {
+            id,
+            timestamp: Utc::now(),
+            question: reading.question().to_string(),
+            method,
+            randomness,
+            throws: reading.line_values().to_vec(),
+            present: reading.present().number,
+            future: reading.future().map(|hex| hex.number),
+            changing_lines,
+            casting: reading.casting().cloned(),
+            fallback: reading.fallback().map(str::to_string),
+            signatures: reading.signatures().to_vec(),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("cannot open journal {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        Ok(entry)
+    }
+
+    /// Returns the entry with the given identifier.
+    pub fn get(&self, id: u64) -> Result<JournalEntry> {
+        self.entries()?
+            .into_iter()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| anyhow!("no reading with ID {} in the journal", id))
+    }
+
+    /// Returns all the entries that match the query.
+    pub fn search(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .filter(|entry| query.matches(entry))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{entropy::ThreadRandom, iching::generate_reading};
+
+    /// Verifies recording readings and reading them back from the journal.
+    #[test]
+    fn test_record_and_get() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let journal = Journal::open(&dir.path().join("nested").join("journal.jsonl"));
+        assert!(journal.entries()?.is_empty());
+
+        let first = reading_from_throws(&[7, 7, 7, 8, 8, 8], "First question")?;
+        let second = reading_from_throws(&[6, 7, 8, 9, 7, 8], "Second question")?;
+        journal.record(
+            &first,
+            Some(ReadingMethod::Coin),
+            RandomnessMode::Pseudorandom,
+        )?;
+        let entry = journal.record(
+            &second,
+            Some(ReadingMethod::YarrowStalks),
+            RandomnessMode::Random,
+        )?;
+        assert_eq!(entry.id, 2);
+        assert_eq!(entry.throws, vec![6, 7, 8, 9, 7, 8]);
+        assert_eq!(entry.changing_lines, vec![0, 3]);
+
+        let entries = journal.entries()?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].present, 11);
+        assert_eq!(entries[0].future, None);
+        assert_eq!(journal.get(2)?, entry);
+        assert_eq!(journal.get(2)?.reading()?, second);
+        assert!(journal.get(3).is_err());
+
+        // Readings that were cast keep their casting in the journal.
+        let cast = generate_reading(
+            ReadingMethod::YarrowStalks,
+            &mut ThreadRandom::new(),
+            "Third question",
+        )?;
+        let entry = journal.record(
+            &cast,
+            Some(ReadingMethod::YarrowStalks),
+            RandomnessMode::Pseudorandom,
+        )?;
+        assert!(entry.casting.is_some());
+        assert_eq!(journal.get(3)?.reading()?, cast);
+
+        // Readings cast by hand without a known method record none.
+        let entry = journal.record(&first, None, RandomnessMode::Manual)?;
+        assert_eq!(journal.get(4)?, entry);
+        assert!(!serde_json::to_string(&entry)?.contains("\"method\""));
+        Ok(())
+    }
+
+    /// Verifies searching the journal by hexagram and question.
+    #[test]
+    fn test_search() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let journal = Journal::open(&dir.path().join("journal.jsonl"));
+        let readings = [
+            ([7, 7, 7, 8, 8, 8], "About work"),
+            ([6, 7, 8, 9, 7, 8], "About family"),
+            ([8, 7, 8, 8, 7, 8], "About work again"),
+        ];
+        for (throws, question) in readings {
+            let reading = reading_from_throws(&throws, question)?;
+            journal.record(
+                &reading,
+                Some(ReadingMethod::Coin),
+                RandomnessMode::Pseudorandom,
+            )?;
+        }
+
+        let ids = |query: JournalQuery| -> Result<Vec<u64>> {
+            Ok(journal.search(&query)?.iter().map(|e| e.id).collect())
+        };
+        assert_eq!(ids(JournalQuery::default())?, vec![1, 2, 3]);
+        assert_eq!(
+            ids(JournalQuery {
+                hexagram: Some(29),
+                ..Default::default()
+            })?,
+            vec![3]
+        );
+        assert_eq!(
+            ids(JournalQuery {
+                hexagram: Some(60),
+                ..Default::default()
+            })?,
+            vec![2]
+        );
+        assert_eq!(
+            ids(JournalQuery {
+                question: Some("WORK".to_string()),
+                ..Default::default()
+            })?,
+            vec![1, 3]
+        );
+        Ok(())
+    }
+
+    /// Verifies that malformed entries are reported with their location.
+    #[test]
+    fn test_malformed_journal() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("journal.jsonl");
+        fs::write(&path, "{\"id\": 1}\n")?;
+        let error = Journal::open(&path).entries().unwrap_err().to_string();
+        assert!(error.contains("journal.jsonl:1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+//! Library for generating I Ching readings and analyzing hexagrams and sequences of hexagrams.
+//!
+//! The core types ([`Line`], [`Trigram`], [`Hexagram`]) and the logic to perform a reading
+//! ([`generate_reading`]) live in the [`iching`] module. The tools to analyze hexagrams and
+//! sequences of hexagrams ([`HexagramAnalysis`], [`HexagramSearcher`], [`SequenceAnalysis`]) live
+//! in the [`iching_analyzer`] module, which caches the shortest paths between every pair of
+//! hexagrams in the [`distance_matrix`] module and searches them over the compact hexagrams of the
+//! [`hexagram_bits`] module. The orderings of the hexagrams that can be analyzed
+//! live in the [`sequences`] module, the Monte Carlo tests of their significance in the
+//! [`significance`] module, and the search for orderings with the fewest operations in the
+//! [`optimizer`] module. The bundled texts of the hexagrams live in the [`texts`] module, and other
+//! translations can be loaded from disk with the [`text_pack`] module. The sources of randomness
+//! used to cast readings live in the [`entropy`] module, and the client for random.org and a local
+//! stand-in for it live in the [`random_org`] module. The lunar calendar used by the plum blossom
+//! method lives in the [`plum_blossom`] module. The most commonly used items of all modules are
+//! re-exported at the root of the crate.
+//!
+//! ```no_run
+//! use iching::{generate_reading, ReadingMethod, ThreadRandom};
+//!
+//! let reading = generate_reading(
+//!     ReadingMethod::YarrowStalks,
+//!     &mut ThreadRandom::new(),
+//!     "What should I focus on today?",
+//! )
+//! .unwrap();
+//! println!("Present hexagram: {}", reading.present().number);
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod distance_matrix;
+pub mod entropy;
+pub mod hexagram_bits;
+pub mod iching;
+pub mod iching_analyzer;
+pub mod journal;
+pub mod manual;
+pub mod optimizer;
+pub mod output;
+pub mod plum_blossom;
+pub mod random_org;
+pub mod sequences;
+pub mod significance;
+pub mod text_pack;
+pub mod texts;
+
+pub use crate::distance_matrix::{DistanceMatrix, DistanceMetric, DistanceTable, DISTANCE_MATRIX};
+pub use crate::entropy::{
+    ByteStream, EntropySource, Fallback, OsRandom, SeededRandom, ThreadRandom,
+};
+pub use crate::hexagram_bits::{HexagramBits, HEXAGRAM_BITS, HEXAGRAM_NUMBERS};
+pub use crate::iching::{
+    create_hexagram, generate_reading, reading_from_casting, reading_from_throws, seeded_rng,
+    ApplicableText, Casting, Hexagram, HexagramEntry, HexagramLine, Line, RandomnessMode, Reading,
+    ReadingMethod, TextReference, Trigram, TrigramLine, YarrowSplit, HEXAGRAMS, HEXAGRAM_INDEX,
+};
+pub use crate::iching_analyzer::{
+    count_line_changes, find_min_random_sequence, king_wen, print_shortest_path, HexagramAnalysis,
+    HexagramSearcher, PairAnalysis, PairRelation, Path, SearchOperation, SequenceAnalysis,
+    SequenceComparison, SequencesComparison, ShortestPathGraph, ShortestPathIter, ShortestPaths,
+};
+pub use crate::journal::{Journal, JournalEntry, JournalQuery};
+pub use crate::manual::{prompt_reading, reading_from_lines};
+pub use crate::optimizer::{
+    optimize_sequence, Objective, Optimization, OptimizerOptions, OptimizerProgress,
+};
+pub use crate::output::{OutputFormat, ReadingOutput};
+pub use crate::plum_blossom::{numbers_casting, LunarTime};
+pub use crate::random_org::{
+    load_private_key, load_public_key, verify_reading, RandomOrg, RandomOrgOptions, SignedRandom,
+    StandInServer, Verification,
+};
+pub use crate::sequences::{load_sequence, resolve_sequence, Sequence};
+pub use crate::significance::{
+    HistogramBin, Metric, MetricDistribution, ShuffleConstraint, SignificanceTest,
+};
+pub use crate::text_pack::{TextPack, TextPackEntry, BUILTIN_TEXT_PACK, BUILTIN_TRANSLATION};
+pub use crate::texts::{hexagram_text, HexagramText, HEXAGRAM_TEXTS};
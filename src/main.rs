@@ -1,21 +1,30 @@
 //! CLI utility for generating I Ching readings and analyzing hexagrams.
 
-pub mod iching;
-pub mod iching_analyzer;
-
-use anyhow::Result;
+use anyhow::{bail, Result};
+use chrono::NaiveDateTime;
 use clap::{Parser, Subcommand};
-use iching_analyzer::{
-    find_min_random_sequence, king_wen, print_shortest_path, HexagramAnalysis, HexagramSearcher,
-    SequenceAnalysis,
+use iching::{
+    find_min_random_sequence, generate_reading, king_wen, load_private_key, load_public_key,
+    load_sequence, numbers_casting, optimize_sequence, output, prompt_reading,
+    random_org::RANDOM_ORG_API_KEY_ENV_VAR, reading_from_casting, reading_from_lines,
+    resolve_sequence, ByteStream, Casting, DistanceMetric, DistanceTable, EntropySource, Fallback,
+    Hexagram, HexagramAnalysis, HexagramSearcher, Journal, JournalEntry, JournalQuery, LunarTime,
+    Objective, Optimization, OptimizerOptions, OsRandom, OutputFormat, RandomOrg, RandomOrgOptions,
+    RandomnessMode, Reading, ReadingMethod, ReadingOutput, SeededRandom, Sequence,
+    SequenceAnalysis, SequenceComparison, SequencesComparison, ShortestPaths, ShuffleConstraint,
+    SignificanceTest, StandInServer, TextPack, ThreadRandom, Verification, BUILTIN_TRANSLATION,
+    DISTANCE_MATRIX,
+};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
 };
-
-use crate::iching::{RandomnessMode, ReadingMethod};
 
 /// Contains subcommands used for manipulating git repositories containing Trane courses.
 #[derive(Clone, Debug, Subcommand)]
 enum AnalyzeSubcommand {
-    #[clap(about = "Compare a random sequence to King Wen's sequence")]
+    #[clap(about = "Compare a random sequence to King Wen's sequence or to another ordering")]
     CompareKingWen {
         #[clap(
             help = "The number of random sequences to generate. Only the one with the least \
@@ -24,29 +33,186 @@ enum AnalyzeSubcommand {
         #[clap(default_value = "1")]
         #[clap(short, long)]
         num_sequences: usize,
+
+        #[clap(help = "The ordering of the hexagrams to compare against random shuffles of it")]
+        #[clap(long, default_value_t = Sequence::KingWen)]
+        sequence: Sequence,
+
+        #[clap(
+            help = "A file with the ordering to compare against, one hexagram per line, used \
+            instead of --sequence"
+        )]
+        #[clap(long, conflicts_with = "sequence")]
+        sequence_file: Option<PathBuf>,
+    },
+
+    #[clap(about = "Compare the analyses of several sequences side by side")]
+    CompareSequences {
+        #[clap(
+            help = "The sequences to compare, each either the name of an ordering (king-wen, \
+            fu-xi, mawangdui, or eight-palaces) or a file with one hexagram per line"
+        )]
+        #[clap(required = true, num_args = 2..)]
+        sequences: Vec<String>,
+    },
+
+    #[clap(about = "Print the shortest distances between every pair of hexagrams")]
+    DistanceMatrix {
+        #[clap(help = "The quantity to print for each pair of hexagrams")]
+        #[clap(long, default_value_t = DistanceMetric::Ops)]
+        metric: DistanceMetric,
+
+        #[clap(
+            help = "Print every quantity as CSV, with a row for each pair of hexagrams, instead \
+            of a grid"
+        )]
+        #[clap(long)]
+        csv: bool,
+
+        #[clap(help = "Write every quantity as CSV to this file instead of printing")]
+        #[clap(short, long)]
+        output: Option<PathBuf>,
     },
 
     #[clap(about = "Print an analysis of the given hexagram")]
     Hexagram {
-        #[clap(help = "The hexagram to analyze")]
-        number: usize,
+        #[clap(
+            help = "The hexagram to analyze, as its number, its lines from bottom to top (010110), \
+            its name (Ji Ji), its trigrams (\"Kan over Li\" or Kan/Li), or its symbol"
+        )]
+        hexagram: Hexagram,
     },
 
     #[clap(about = "Print an analysis of King Wen's sequence")]
     KingWen,
 
+    #[clap(about = "Print an analysis of an ordering of the hexagrams")]
+    Sequence {
+        #[clap(help = "The ordering of the hexagrams to analyze")]
+        #[clap(default_value_t = Sequence::KingWen)]
+        sequence: Sequence,
+
+        #[clap(
+            help = "A file with the ordering to analyze, one hexagram per line, used instead of \
+            the named ordering"
+        )]
+        #[clap(long, conflicts_with = "sequence")]
+        file: Option<PathBuf>,
+    },
+
+    #[clap(about = "Search for the ordering of the hexagrams with the lowest cost")]
+    Optimize {
+        #[clap(help = "The ordering from which to start the search")]
+        #[clap(long, default_value_t = Sequence::KingWen)]
+        sequence: Sequence,
+
+        #[clap(
+            help = "A file with the ordering from which to start the search, one hexagram per \
+            line, used instead of --sequence"
+        )]
+        #[clap(long, conflicts_with = "sequence")]
+        sequence_file: Option<PathBuf>,
+
+        #[clap(help = "The quantity to minimize")]
+        #[clap(long, default_value_t = Objective::Ops)]
+        objective: Objective,
+
+        #[clap(help = "The weight of each operation in the weighted objective")]
+        #[clap(long, default_value = "1.0")]
+        ops_weight: f64,
+
+        #[clap(help = "The weight of each line change in the weighted objective")]
+        #[clap(long, default_value = "1.0")]
+        line_changes_weight: f64,
+
+        #[clap(help = "The number of seconds after which the search stops")]
+        #[clap(long, default_value = "10")]
+        time_budget: u64,
+
+        #[clap(help = "The number of iterations after which the search stops")]
+        #[clap(long)]
+        max_iterations: Option<u64>,
+    },
+
+    #[clap(
+        about = "Test how likely random sequences are to score like an ordering of the hexagrams"
+    )]
+    Significance {
+        #[clap(help = "The number of random sequences to generate")]
+        #[clap(default_value = "1000")]
+        #[clap(short, long)]
+        num_sequences: usize,
+
+        #[clap(help = "The ordering of the hexagrams to test")]
+        #[clap(long, default_value_t = Sequence::KingWen)]
+        sequence: Sequence,
+
+        #[clap(
+            help = "A file with the ordering to test, one hexagram per line, used instead of \
+            --sequence"
+        )]
+        #[clap(long, conflicts_with = "sequence")]
+        sequence_file: Option<PathBuf>,
+
+        #[clap(help = "The constraint that the random sequences must respect")]
+        #[clap(long, default_value_t = ShuffleConstraint::None)]
+        constraint: ShuffleConstraint,
+
+        #[clap(help = "The number of bins of the histogram of each metric")]
+        #[clap(long, default_value = "20")]
+        bins: usize,
+    },
+
     #[clap(about = "Find the shortest path between two hexagrams")]
     ShortestDistance {
-        #[clap(help = "The hexagram from which to start")]
-        start: usize,
+        #[clap(
+            help = "The hexagram from which to start, in any of the forms accepted by \
+            `analyze hexagram`"
+        )]
+        start: Hexagram,
 
-        #[clap(help = "The hexagram to reach")]
-        end: usize,
+        #[clap(help = "The hexagram to reach, in any of the forms accepted by `analyze hexagram`")]
+        end: Hexagram,
 
         #[clap(help = "Print all shortest paths instead of the ones with the least line changes")]
         #[clap(short, long)]
         #[clap(default_value = "false")]
         all: bool,
+
+        #[clap(help = "Print at most this many paths, while still counting all of them")]
+        #[clap(short, long)]
+        limit: Option<usize>,
+    },
+}
+
+/// Contains subcommands used to inspect the journal of readings.
+#[derive(Clone, Debug, Subcommand)]
+enum JournalSubcommand {
+    #[clap(about = "List all the readings in the journal")]
+    List,
+
+    #[clap(about = "Show the reading with the given ID")]
+    Show {
+        #[clap(help = "The ID of the reading")]
+        id: u64,
+    },
+
+    #[clap(about = "Search the readings in the journal")]
+    Search {
+        #[clap(help = "Only show readings in which this is the present or future hexagram")]
+        #[clap(long)]
+        hexagram: Option<Hexagram>,
+
+        #[clap(help = "Only show readings whose question contains this text")]
+        #[clap(long)]
+        question: Option<String>,
+    },
+
+    #[clap(about = "Export all the readings in the journal as JSON or YAML")]
+    Export {
+        #[clap(help = "The file to which to write the readings. Defaults to standard output")]
+        #[clap(short, long)]
+        output: Option<PathBuf>,
     },
 }
 
@@ -56,6 +222,61 @@ enum IChingSubcommand {
     #[clap(about = "Sub-commands to analyze hexagrams")]
     #[clap(subcommand)]
     Analyze(AnalyzeSubcommand),
+
+    #[clap(about = "Interpret a casting performed by hand, such as with real coins or stalks")]
+    Cast {
+        #[clap(
+            help = "The values of the lines, from bottom to top, separated by commas: 6 (old \
+            yin), 7 (young yang), 8 (young yin), or 9 (old yang). If not given, the value of \
+            each line is asked in turn"
+        )]
+        #[clap(short, long, value_delimiter = ',')]
+        lines: Option<Vec<u8>>,
+
+        #[clap(help = "The method used to cast the lines by hand, recorded in the journal")]
+        #[clap(short, long)]
+        method: Option<ReadingMethod>,
+
+        #[clap(help = "The optional question asked of the I Ching")]
+        #[clap(short, long, default_value = "")]
+        question: String,
+
+        #[clap(help = "Save the reading to the journal")]
+        #[clap(short, long)]
+        journal: bool,
+    },
+
+    #[clap(about = "Sub-commands to inspect the journal of readings")]
+    #[clap(subcommand)]
+    Journal(JournalSubcommand),
+
+    #[clap(
+        about = "Run a local stand-in for random.org that draws numbers from a seeded generator"
+    )]
+    ServeRandomOrg {
+        #[clap(help = "The address on which to listen")]
+        #[clap(short, long, default_value = "127.0.0.1:8080")]
+        address: String,
+
+        #[clap(
+            help = "The PEM file containing the RSA private key used to sign the numbers \
+            requested with an API key. Signed numbers are not served if not given"
+        )]
+        #[clap(long)]
+        signing_key: Option<PathBuf>,
+    },
+
+    #[clap(
+        about = "Verify that a reading in the journal was cast with numbers signed by random.org"
+    )]
+    Verify {
+        #[clap(help = "The ID of the reading")]
+        id: u64,
+
+        #[clap(help = "The PEM file containing the public key of random.org")]
+        #[clap(long)]
+        public_key: PathBuf,
+    },
 }
 
 /// Arguments for the CLI.
@@ -66,56 +287,428 @@ struct Args {
     #[arg(short, long, default_value_t = ReadingMethod::YarrowStalks)]
     method: ReadingMethod,
 
-    /// Whether to use random.org or a pseudo-random number generator to generate the reading.
+    /// The source of randomness used to generate the reading.
     #[arg(short, long, default_value_t = RandomnessMode::Random)]
     randomness: RandomnessMode,
 
+    /// The local date and time from which to cast a plum blossom reading, such as
+    /// 2025-01-16T15:30:00. It is converted to the lunar calendar. Defaults to the current time.
+    #[arg(long, conflicts_with_all = ["lunar_time", "numbers"])]
+    time: Option<NaiveDateTime>,
+
+    /// The time in the lunar calendar from which to cast a plum blossom reading: the earthly branch
+    /// of the year, the lunar month, the lunar day, and the earthly branch of the double hour, as
+    /// numbers separated by commas, such as 5,12,17,9.
+    #[arg(long, value_delimiter = ',', conflicts_with = "numbers")]
+    lunar_time: Option<Vec<u8>>,
+
+    /// The two numbers from which to cast a plum blossom reading, separated by a comma. The first
+    /// gives the upper trigram, the second the lower trigram, and their sum the changing line.
+    #[arg(long, value_delimiter = ',')]
+    numbers: Option<Vec<u32>>,
+
     /// The optional question to ask the I Ching.
     #[arg(short, long, default_value = "")]
     question: String,
 
+    /// The translation used to print the texts of the reading. Either the name of a text pack in
+    /// the texts directory, the path to a text pack, or "legge" for the bundled translation.
+    #[arg(short, long, default_value = BUILTIN_TRANSLATION)]
+    translation: String,
+
+    /// The directory containing the text packs.
+    #[arg(long)]
+    texts_dir: Option<PathBuf>,
+
+    /// The format used to print the results.
+    #[arg(short, long, global = true, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// The seed used to generate reproducible results. Implies pseudo-random mode, so the same seed
+    /// and method always generate the same reading.
+    #[arg(short, long, global = true)]
+    seed: Option<u64>,
+
+    /// The base URL of random.org or of a compatible service, used in random mode. Defaults to the
+    /// value of the ICHING_RANDOM_ORG_URL environment variable or to https://www.random.org.
+    #[arg(long)]
+    random_org_url: Option<String>,
+
+    /// The number of seconds to wait for each request to random.org.
+    #[arg(long, default_value_t = 10)]
+    random_org_timeout: u64,
+
+    /// The number of times a failed request to random.org is retried, waiting twice as long
+    /// before each retry.
+    #[arg(long, default_value_t = 3)]
+    random_org_retries: u32,
+
+    /// The API key used to request numbers signed by random.org, which can later be verified with
+    /// the verify subcommand. Defaults to the value of the ICHING_RANDOM_ORG_API_KEY environment
+    /// variable. If not set, the numbers are not signed.
+    #[arg(long)]
+    random_org_api_key: Option<String>,
+
+    /// Fall back to the pseudo-random number generator if random.org is unavailable. The fallback
+    /// is recorded on the reading.
+    #[arg(long)]
+    fallback: bool,
+
+    /// The file from which random bytes are read in stream mode, such as /dev/hwrng or a named
+    /// pipe written to by a hardware random number generator daemon.
+    #[arg(long)]
+    entropy_file: Option<PathBuf>,
+
+    /// Save the reading to the journal.
+    #[arg(short, long)]
+    journal: bool,
+
+    /// The file containing the journal. Defaults to the value of the ICHING_JOURNAL environment
+    /// variable or to ~/.iching/journal.jsonl.
+    #[arg(long, global = true)]
+    journal_file: Option<PathBuf>,
+
     #[clap(subcommand)]
     subcommand: Option<IChingSubcommand>,
 }
 
+/// Returns the plum blossom casting selected by the arguments, from the given time, lunar date or
+/// numbers, or from the current time if none is given. Returns nothing if the reading uses another
+/// method.
+fn plum_blossom_casting(args: &Args) -> Result<Option<Casting>> {
+    if args.time.is_none() && args.lunar_time.is_none() && args.numbers.is_none() {
+        return match args.method {
+            ReadingMethod::PlumBlossom => Ok(Some(LunarTime::now()?.casting())),
+            _ => Ok(None),
+        };
+    }
+    if args.method != ReadingMethod::PlumBlossom {
+        bail!("--time, --lunar-time, and --numbers require the plum-blossom method");
+    }
+
+    let casting = match (&args.time, &args.lunar_time, &args.numbers) {
+        (Some(time), _, _) => LunarTime::from_datetime(time)?.casting(),
+        (_, Some(lunar_time), _) => match lunar_time[..] {
+            [year, month, day, hour] => LunarTime::new(year, month, day, hour)?.casting(),
+            _ => bail!("--lunar-time requires four numbers: year, month, day, and hour"),
+        },
+        (_, _, Some(numbers)) => match numbers[..] {
+            [first, second] => numbers_casting(first, second)?,
+            _ => bail!("--numbers requires two numbers"),
+        },
+        _ => unreachable!(),
+    };
+    Ok(Some(casting))
+}
+
+/// Returns the hexagrams of the ordering loaded from the given file, if any, or of the given
+/// traditional ordering otherwise.
+fn load_ordering(sequence: Sequence, file: Option<&Path>) -> Result<Vec<usize>> {
+    match file {
+        Some(path) => load_sequence(path),
+        None => sequence.hexagrams(),
+    }
+}
+
+/// Returns the source of randomness selected by the arguments, along with the randomness mode to
+/// record in the journal.
+fn entropy_source(args: &Args) -> Result<(Box<dyn EntropySource>, RandomnessMode)> {
+    if let Some(seed) = args.seed {
+        return Ok((
+            Box::new(SeededRandom::new(seed)),
+            RandomnessMode::Pseudorandom,
+        ));
+    }
+
+    let source: Box<dyn EntropySource> = match args.randomness {
+        RandomnessMode::Random => {
+            let options = RandomOrgOptions {
+                timeout: Duration::from_secs(args.random_org_timeout),
+                retries: args.random_org_retries,
+                api_key: args.random_org_api_key.clone().or_else(|| {
+                    env::var(RANDOM_ORG_API_KEY_ENV_VAR)
+                        .ok()
+                        .filter(|key| !key.trim().is_empty())
+                }),
+                ..Default::default()
+            };
+            let random_org = match &args.random_org_url {
+                Some(url) => RandomOrg::with_options(url, options),
+                None => RandomOrg::from_env_with_options(options),
+            };
+            if args.fallback {
+                Box::new(Fallback::new(
+                    Box::new(random_org),
+                    Box::new(ThreadRandom::new()),
+                ))
+            } else {
+                Box::new(random_org)
+            }
+        }
+        RandomnessMode::Pseudorandom => Box::new(ThreadRandom::new()),
+        RandomnessMode::System => Box::new(OsRandom),
+        RandomnessMode::Stream => match &args.entropy_file {
+            Some(path) => Box::new(ByteStream::open(path)?),
+            None => bail!("the stream randomness mode requires --entropy-file"),
+        },
+        RandomnessMode::Manual | RandomnessMode::Deterministic => {
+            bail!("the {} randomness mode cannot be selected", args.randomness)
+        }
+    };
+    Ok((source, args.randomness))
+}
+
+/// Saves the reading to the journal, if one is given, and prints it.
+fn finish_reading(
+    args: &Args,
+    journal: Option<&Journal>,
+    texts: &TextPack,
+    reading: Reading,
+    method: Option<ReadingMethod>,
+    randomness: RandomnessMode,
+) -> Result<()> {
+    if let Some(journal) = journal {
+        let entry = journal.record(&reading, method, randomness)?;
+        eprintln!(
+            "Saved reading #{} to {}",
+            entry.id,
+            journal.path().display()
+        );
+    }
+    output::print(&ReadingOutput::new(reading, texts), args.format, |out| {
+        out.reading.print_with_texts(texts)
+    })
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
+    let journal = Journal::open(
+        &args
+            .journal_file
+            .clone()
+            .unwrap_or_else(Journal::default_path),
+    );
 
-    match args.subcommand {
+    match args.subcommand.clone() {
         None => {
-            let result = iching::generate_reading(args.method, args.randomness, &args.question)?;
-            result.print();
+            let texts = TextPack::find(&args.translation, args.texts_dir.as_deref())?;
+            // Plum blossom readings are derived from a time or from numbers, so only the other
+            // methods need a source of randomness.
+            let (result, randomness) = match plum_blossom_casting(&args)? {
+                Some(casting) => (
+                    reading_from_casting(casting, &args.question)?,
+                    RandomnessMode::Deterministic,
+                ),
+                None => {
+                    let (mut entropy, randomness) = entropy_source(&args)?;
+                    let reading =
+                        generate_reading(args.method.clone(), entropy.as_mut(), &args.question)?;
+                    (reading, randomness)
+                }
+            };
+            finish_reading(
+                &args,
+                args.journal.then_some(&journal),
+                &texts,
+                result,
+                Some(args.method.clone()),
+                randomness,
+            )?;
         }
         Some(subcommand) => {
             match subcommand {
-                IChingSubcommand::Analyze(AnalyzeSubcommand::CompareKingWen { num_sequences }) => {
-                    let king_wen_analysis = SequenceAnalysis::new(king_wen())?;
-                    let min_analysis = find_min_random_sequence(num_sequences)?;
-                    king_wen_analysis.print_comparison(&min_analysis);
+                IChingSubcommand::Cast {
+                    lines,
+                    method,
+                    question,
+                    journal: save,
+                } => {
+                    let texts = TextPack::find(&args.translation, args.texts_dir.as_deref())?;
+                    let reading = match lines {
+                        Some(lines) => reading_from_lines(&lines, &question)?,
+                        None => {
+                            prompt_reading(&mut io::stdin().lock(), &mut io::stderr(), &question)?
+                        }
+                    };
+                    finish_reading(
+                        &args,
+                        save.then_some(&journal),
+                        &texts,
+                        reading,
+                        method,
+                        RandomnessMode::Manual,
+                    )?;
                 }
-                IChingSubcommand::Analyze(AnalyzeSubcommand::Hexagram { number }) => {
-                    let analysis = HexagramAnalysis::new(number)?;
-                    analysis.print();
+                IChingSubcommand::Analyze(AnalyzeSubcommand::CompareKingWen {
+                    num_sequences,
+                    sequence,
+                    sequence_file,
+                }) => {
+                    let reference = load_ordering(sequence, sequence_file.as_deref())?;
+                    let comparison = SequenceComparison {
+                        other: find_min_random_sequence(&reference, num_sequences, args.seed)?,
+                        reference: SequenceAnalysis::new(reference)?,
+                    };
+                    output::print(&comparison, args.format, SequenceComparison::print)?;
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::CompareSequences { sequences }) => {
+                    let sequences = sequences
+                        .into_iter()
+                        .map(|name| Ok((name.clone(), resolve_sequence(&name)?)))
+                        .collect::<Result<Vec<_>>>()?;
+                    let comparison = SequencesComparison::new(sequences)?;
+                    output::print(&comparison, args.format, SequencesComparison::print)?;
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::DistanceMatrix {
+                    metric,
+                    csv,
+                    output: path,
+                }) => {
+                    let table = DistanceTable::new(&DISTANCE_MATRIX)?;
+                    match path {
+                        Some(path) => fs::write(path, table.to_csv())?,
+                        None if csv => print!("{}", table.to_csv()),
+                        None => output::print(&table, args.format, |table| table.print(metric))?,
+                    }
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::Hexagram { hexagram }) => {
+                    let analysis = HexagramAnalysis::new(hexagram.number as usize)?;
+                    output::print(&analysis, args.format, HexagramAnalysis::print)?;
                 }
                 IChingSubcommand::Analyze(AnalyzeSubcommand::KingWen) => {
                     let analysis = SequenceAnalysis::new(king_wen())?;
-                    analysis.print();
+                    output::print(&analysis, args.format, SequenceAnalysis::print)?;
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::Sequence { sequence, file }) => {
+                    let analysis =
+                        SequenceAnalysis::new(load_ordering(sequence, file.as_deref())?)?;
+                    output::print(&analysis, args.format, SequenceAnalysis::print)?;
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::Optimize {
+                    sequence,
+                    sequence_file,
+                    objective,
+                    ops_weight,
+                    line_changes_weight,
+                    time_budget,
+                    max_iterations,
+                }) => {
+                    let options = OptimizerOptions {
+                        objective,
+                        ops_weight,
+                        line_changes_weight,
+                        time_budget: Duration::from_secs(time_budget),
+                        max_iterations,
+                        seed: args.seed,
+                        ..Default::default()
+                    };
+                    let optimization = optimize_sequence(
+                        load_ordering(sequence, sequence_file.as_deref())?,
+                        &options,
+                        |progress| {
+                            eprintln!(
+                                "Iteration {} after {:.1}s: current cost {}, best cost {}, \
+                                temperature {:.3}",
+                                progress.iteration,
+                                progress.elapsed.as_secs_f64(),
+                                progress.current_cost,
+                                progress.best_cost,
+                                progress.temperature
+                            )
+                        },
+                    )?;
+                    output::print(&optimization, args.format, Optimization::print)?;
+                }
+                IChingSubcommand::Analyze(AnalyzeSubcommand::Significance {
+                    num_sequences,
+                    sequence,
+                    sequence_file,
+                    constraint,
+                    bins,
+                }) => {
+                    let test = SignificanceTest::new(
+                        load_ordering(sequence, sequence_file.as_deref())?,
+                        num_sequences,
+                        constraint,
+                        args.seed,
+                        bins,
+                    )?;
+                    output::print(&test, args.format, SignificanceTest::print)?;
                 }
                 IChingSubcommand::Analyze(AnalyzeSubcommand::ShortestDistance {
                     start,
                     end,
                     all,
+                    limit,
                 }) => {
-                    // Perform the search.
-                    let searcher = HexagramSearcher::new(start, end)?;
-                    let paths = searcher.find_shortest_paths(all);
-
-                    // Print all the paths
-                    println!(">>>>> Shortest path search from {} to {}", start, end);
-                    println!();
-                    println!(">>> Shortest path search found {} path(s)", paths.len());
-                    println!();
-                    print_shortest_path(start, end, &paths)
+                    // Perform the search and print the paths, up to the limit if there is one.
+                    let (start, end) = (start.number as usize, end.number as usize);
+                    let graph = HexagramSearcher::new(start, end)?.search(all);
+                    let paths = ShortestPaths {
+                        start,
+                        end,
+                        num_paths: graph.num_paths(),
+                        paths: graph.paths().take(limit.unwrap_or(usize::MAX)).collect(),
+                    };
+                    output::print(&paths, args.format, ShortestPaths::print)?;
+                }
+                IChingSubcommand::Journal(JournalSubcommand::List) => {
+                    let entries = journal.entries()?;
+                    output::print(&entries, args.format, |entries| {
+                        entries.iter().for_each(JournalEntry::print_summary)
+                    })?;
+                }
+                IChingSubcommand::Journal(JournalSubcommand::Show { id }) => {
+                    let entry = journal.get(id)?;
+                    match args.format {
+                        OutputFormat::Text => {
+                            let texts =
+                                TextPack::find(&args.translation, args.texts_dir.as_deref())?;
+                            entry.print_with_texts(&texts)?;
+                        }
+                        _ => output::print(&entry, args.format, |_| {})?,
+                    }
+                }
+                IChingSubcommand::Journal(JournalSubcommand::Search { hexagram, question }) => {
+                    let entries = journal.search(&JournalQuery {
+                        hexagram: hexagram.map(|hexagram| hexagram.number),
+                        question,
+                    })?;
+                    output::print(&entries, args.format, |entries| {
+                        entries.iter().for_each(JournalEntry::print_summary)
+                    })?;
+                }
+                IChingSubcommand::Journal(JournalSubcommand::Export { output: path }) => {
+                    // The journal is exported as JSON unless YAML is requested.
+                    let format = match args.format {
+                        OutputFormat::Yaml => OutputFormat::Yaml,
+                        OutputFormat::Text | OutputFormat::Json => OutputFormat::Json,
+                    };
+                    let contents = output::to_string(&journal.entries()?, format)?;
+                    match path {
+                        Some(path) => fs::write(path, contents)?,
+                        None => print!("{}", contents),
+                    }
+                }
+                IChingSubcommand::ServeRandomOrg {
+                    address,
+                    signing_key,
+                } => {
+                    let seed = args.seed.unwrap_or_else(rand::random);
+                    let signing_key = signing_key.as_deref().map(load_private_key).transpose()?;
+                    let server = StandInServer::bind(&address, seed, signing_key)?;
+                    eprintln!(
+                        "Serving random numbers with seed {} at {}",
+                        seed,
+                        server.url()
+                    );
+                    server.wait();
+                }
+                IChingSubcommand::Verify { id, public_key } => {
+                    let public_key = load_public_key(&public_key)?;
+                    let reading = journal.get(id)?.reading()?;
+                    let verification = Verification::new(id, &reading, &public_key)?;
+                    output::print(&verification, args.format, Verification::print)?;
                 }
             }
         }
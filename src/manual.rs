@@ -0,0 +1,133 @@
+//! Module containing the logic to interpret a casting performed by hand.
+//!
+//! The lines cast with real coins, stalks, or any other method are entered as their values, from
+//! the bottom line to the top one: 6 for old yin, 7 for young yang, 8 for young yin, and 9 for old
+//! yang. The values go through the same conversion as the readings generated by the crate, so the
+//! result is a normal [`Reading`].
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::io::{BufRead, Write};
+
+use crate::iching::{reading_from_throws, Reading};
+
+/// Verifies that the given value is a valid value for the line at the given position, counting
+/// from 1 at the bottom.
+fn check_line_value(line: usize, value: u8) -> Result<u8> {
+    if !(6..=9).contains(&value) {
+        bail!(
+            "invalid value for line {}: {} (expected 6, 7, 8, or 9)",
+            line,
+            value
+        );
+    }
+    Ok(value)
+}
+
+/// Parses the value of the line at the given position, counting from 1 at the bottom.
+fn parse_line_value(line: usize, text: &str) -> Result<u8> {
+    let value = text.trim().parse::<u8>().map_err(|_| {
+        anyhow!(
+            "invalid value for line {}: {:?} (expected 6, 7, 8, or 9)",
+            line,
+            text.trim()
+        )
+    })?;
+    check_line_value(line, value)
+}
+
+/// Returns the reading for the given line values, from bottom to top.
+pub fn reading_from_lines(values: &[u8], question: &str) -> Result<Reading> {
+    if values.len() != 6 {
+        bail!("expected the values of 6 lines, got {}", values.len());
+    }
+    for (i, value) in values.iter().enumerate() {
+        check_line_value(i + 1, *value)?;
+    }
+    reading_from_throws(values, question)
+}
+
+/// Asks for the value of each line in turn, from bottom to top, and returns the reading. Invalid
+/// values are reported and asked again. Returns an error if the input ends before all the lines
+/// have been entered.
+pub fn prompt_reading(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+) -> Result<Reading> {
+    writeln!(
+        output,
+        "Enter the value of each line, from the bottom up: 6 (old yin), 7 (young yang), \
+        8 (young yin), or 9 (old yang)."
+    )?;
+    let mut values = Vec::with_capacity(6);
+    while values.len() < 6 {
+        let line = values.len() + 1;
+        write!(output, "Line {}: ", line)?;
+        output.flush()?;
+
+        let mut text = String::new();
+        if input
+            .read_line(&mut text)
+            .context("cannot read the value of the line")?
+            == 0
+        {
+            bail!(
+                "the input ended before the value of line {} was entered",
+                line
+            );
+        }
+        match parse_line_value(line, &text) {
+            Ok(value) => values.push(value),
+            Err(error) => writeln!(output, "{}", error)?,
+        }
+    }
+    reading_from_lines(&values, question)
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+
+    /// Verifies that lines given as values are converted like generated ones and validated.
+    #[test]
+    fn test_lines() -> Result<()> {
+        let reading = reading_from_lines(&[7, 8, 9, 6, 7, 7], "Question")?;
+        assert_eq!(
+            reading,
+            reading_from_throws(&[7, 8, 9, 6, 7, 7], "Question")?
+        );
+        assert_eq!(reading.question(), "Question");
+
+        let error = reading_from_lines(&[7, 8, 9, 5, 7, 7], "")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("line 4: 5"), "{}", error);
+        assert!(reading_from_lines(&[7, 8, 9, 6, 7, 10], "").is_err());
+        assert!(reading_from_lines(&[7, 8, 9], "").is_err());
+        Ok(())
+    }
+
+    /// Verifies that the prompt asks again for invalid values and fails if the input ends early.
+    #[test]
+    fn test_prompt() -> Result<()> {
+        let mut output = Vec::new();
+        let reading = prompt_reading(
+            &mut "7\n8\nnine\n9\n10\n6\n 7 \n7\n".as_bytes(),
+            &mut output,
+            "",
+        )?;
+        assert_eq!(reading.line_values(), [7, 8, 9, 6, 7, 7]);
+        let output = String::from_utf8(output)?;
+        assert!(output.contains("invalid value for line 3: \"nine\""));
+        assert!(output.contains("invalid value for line 4: 10"));
+        assert!(output.ends_with("Line 6: "));
+
+        let error = prompt_reading(&mut "7\n8\n".as_bytes(), &mut Vec::new(), "")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("line 3"), "{}", error);
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+//! Module containing the search for orderings of the hexagrams with the fewest operations.
+//!
+//! Finding the ordering of a set of hexagrams that minimizes the cost of the transitions between
+//! consecutive hexagrams is an instance of the traveling salesman problem, with an asymmetric cost
+//! since not every operation can be undone by another one. The optimizer uses simulated annealing
+//! over the 2-opt move, which reverses a segment of the ordering, the Or-opt move, which moves a
+//! short segment elsewhere, and swaps of two hexagrams.
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    distance_matrix::DISTANCE_MATRIX, iching::seeded_rng, iching_analyzer::SequenceAnalysis,
+};
+
+/// The ratio between the final and the initial temperature of the annealing.
+const FINAL_TEMPERATURE_RATIO: f64 = 1e-3;
+
+/// The longest segment moved by the Or-opt move.
+const MAX_OR_OPT_SEGMENT: usize = 3;
+
+/// The quantity minimized by the optimizer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Objective {
+    /// The total number of operations between consecutive hexagrams.
+    #[default]
+    Ops,
+
+    /// The total number of line changes between consecutive hexagrams.
+    LineChanges,
+
+    /// The weighted sum of the operations and of the line changes.
+    Weighted,
+}
+
+impl Display for Objective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Objective::Ops => write!(f, "ops"),
+            Objective::LineChanges => write!(f, "line-changes"),
+            Objective::Weighted => write!(f, "weighted"),
+        }
+    }
+}
+
+/// The options of the optimizer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimizerOptions {
+    /// The quantity to minimize.
+    pub objective: Objective,
+
+    /// The weight of each operation in the weighted objective.
+    pub ops_weight: f64,
+
+    /// The weight of each line change in the weighted objective.
+    pub line_changes_weight: f64,
+
+    /// The time after which the search stops.
+    pub time_budget: Duration,
+
+    /// The number of iterations after which the search stops, if any. With a seed and a time
+    /// budget long enough to reach it, the search always returns the same sequence.
+    pub max_iterations: Option<u64>,
+
+    /// The seed of the generator used by the search. If not set, the thread-local generator is
+    /// used.
+    pub seed: Option<u64>,
+
+    /// The time between two reports of the progress of the search.
+    pub progress_interval: Duration,
+}
+
+impl Default for OptimizerOptions {
+    fn default() -> Self {
+        Self {
+            objective: Objective::Ops,
+            ops_weight: 1.0,
+            line_changes_weight: 1.0,
+            time_budget: Duration::from_secs(10),
+            max_iterations: None,
+            seed: None,
+            progress_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl OptimizerOptions {
+    /// Returns the cost of a transition with the given number of operations and line changes.
+    fn cost(&self, ops: u64, line_changes: u64) -> f64 {
+        match self.objective {
+            Objective::Ops => ops as f64,
+            Objective::LineChanges => line_changes as f64,
+            Objective::Weighted => {
+                self.ops_weight * ops as f64 + self.line_changes_weight * line_changes as f64
+            }
+        }
+    }
+}
+
+/// A report of the progress of the search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimizerProgress {
+    /// The number of iterations performed so far.
+    pub iteration: u64,
+
+    /// The time elapsed since the search started.
+    pub elapsed: Duration,
+
+    /// The current temperature of the annealing.
+    pub temperature: f64,
+
+    /// The cost of the current sequence.
+    pub current_cost: f64,
+
+    /// The cost of the best sequence found so far.
+    pub best_cost: f64,
+}
+
+/// The result of the search for the sequence with the lowest cost.
+#[derive(Clone, Debug, Serialize)]
+pub struct Optimization {
+    /// The minimized quantity.
+    pub objective: Objective,
+
+    /// The cost of the sequence the search started from.
+    pub initial_cost: f64,
+
+    /// The cost of the best sequence found.
+    pub cost: f64,
+
+    /// The number of iterations performed.
+    pub iterations: u64,
+
+    /// The duration of the search, in seconds.
+    pub elapsed_secs: f64,
+
+    /// The analysis of the best sequence found.
+    pub analysis: SequenceAnalysis,
+}
+
+impl Optimization {
+    /// Prints the result of the search followed by the analysis of the best sequence.
+    pub fn print(&self) {
+        println!(">>>>> Optimization of the sequence of hexagrams");
+        println!();
+        println!(">>> Objective: {}", self.objective);
+        println!(">>> Cost: {} (from {})", self.cost, self.initial_cost);
+        println!(
+            ">>> Iterations: {} in {:.1} seconds",
+            self.iterations, self.elapsed_secs
+        );
+        println!();
+        self.analysis.print();
+    }
+}
+
+/// Returns the cost of the transition between every pair of hexagrams in the sequence, indexed by
+/// their positions in the sequence.
+fn cost_matrix(sequence: &[usize], options: &OptimizerOptions) -> Result<Vec<Vec<f64>>> {
+    sequence
+        .iter()
+        .map(|start| {
+            sequence
+                .iter()
+                .map(|end| {
+                    Ok(options.cost(
+                        DISTANCE_MATRIX.ops(*start, *end)?,
+                        DISTANCE_MATRIX.line_changes(*start, *end)?,
+                    ))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the total cost of the ordering, given as positions in the cost matrix.
+fn total_cost(order: &[usize], costs: &[Vec<f64>]) -> f64 {
+    order
+        .windows(2)
+        .map(|window| costs[window[0]][window[1]])
+        .sum()
+}
+
+/// Applies a random move to the ordering: a 2-opt reversal of a segment, an Or-opt move of a short
+/// segment, or a swap of two positions.
+fn random_move<R: Rng>(order: &mut Vec<usize>, rng: &mut R) {
+    let n = order.len();
+    match rng.gen_range(0..3) {
+        0 => {
+            let i = rng.gen_range(0..n - 1);
+            let j = rng.gen_range(i + 1..n);
+            order[i..=j].reverse();
+        }
+        1 => {
+            let length = rng.gen_range(1..=MAX_OR_OPT_SEGMENT.min(n - 1));
+            let start = rng.gen_range(0..=n - length);
+            let segment: Vec<usize> = order.drain(start..start + length).collect();
+            let position = rng.gen_range(0..=order.len());
+            order.splice(position..position, segment);
+        }
+        _ => {
+            let i = rng.gen_range(0..n);
+            let j = rng.gen_range(0..n);
+            order.swap(i, j);
+        }
+    }
+}
+
+/// Searches for the ordering of the hexagrams in the sequence with the lowest cost, starting from
+/// the sequence itself, and returns the analysis of the best ordering found. The progress of the
+/// search is reported at the interval given in the options and once it ends.
+pub fn optimize_sequence(
+    sequence: Vec<usize>,
+    options: &OptimizerOptions,
+    mut progress: impl FnMut(&OptimizerProgress),
+) -> Result<Optimization> {
+    if sequence.len() < 2 {
+        bail!("the sequence to optimize must contain at least two hexagrams");
+    }
+    if (1..sequence.len()).any(|i| sequence[..i].contains(&sequence[i])) {
+        bail!("the sequence to optimize must not contain any hexagram more than once");
+    }
+    let start_time = Instant::now();
+    let costs = cost_matrix(&sequence, options)?;
+    let mut rng = match options.seed {
+        Some(seed) => seeded_rng(seed),
+        None => ChaCha8Rng::from_rng(rand::thread_rng())?,
+    };
+
+    // Start hot enough to accept moves costing about one average transition.
+    let n = sequence.len();
+    let initial_temperature = costs.iter().flatten().sum::<f64>() / (n * n) as f64;
+    let final_temperature = initial_temperature * FINAL_TEMPERATURE_RATIO;
+
+    let mut current: Vec<usize> = (0..n).collect();
+    let mut current_cost = total_cost(&current, &costs);
+    let initial_cost = current_cost;
+    let mut best = current.clone();
+    let mut best_cost = current_cost;
+    let mut temperature = initial_temperature;
+    let mut iteration = 0;
+    let mut last_report = Instant::now();
+
+    while n > 2 && options.max_iterations.is_none_or(|max| iteration < max) {
+        // Cool down with the fraction of the budget spent, in time or in iterations.
+        let elapsed = start_time.elapsed();
+        let mut fraction = elapsed.as_secs_f64() / options.time_budget.as_secs_f64().max(1e-9);
+        if let Some(max) = options.max_iterations {
+            fraction = fraction.max(iteration as f64 / max as f64);
+        }
+        if fraction >= 1.0 {
+            break;
+        }
+        temperature =
+            initial_temperature * (final_temperature / initial_temperature).powf(fraction);
+
+        let mut candidate = current.clone();
+        random_move(&mut candidate, &mut rng);
+        let candidate_cost = total_cost(&candidate, &costs);
+        let delta = candidate_cost - current_cost;
+        if delta <= 0.0 || rng.gen::<f64>() < (-delta / temperature).exp() {
+            current = candidate;
+            current_cost = candidate_cost;
+            if current_cost < best_cost {
+                best = current.clone();
+                best_cost = current_cost;
+            }
+        }
+        iteration += 1;
+
+        if last_report.elapsed() >= options.progress_interval {
+            last_report = Instant::now();
+            progress(&OptimizerProgress {
+                iteration,
+                elapsed,
+                temperature,
+                current_cost,
+                best_cost,
+            });
+        }
+    }
+
+    let elapsed = start_time.elapsed();
+    progress(&OptimizerProgress {
+        iteration,
+        elapsed,
+        temperature,
+        current_cost,
+        best_cost,
+    });
+    let best_sequence = best.iter().map(|i| sequence[*i]).collect();
+    Ok(Optimization {
+        objective: options.objective,
+        initial_cost,
+        cost: best_cost,
+        iterations: iteration,
+        elapsed_secs: elapsed.as_secs_f64(),
+        analysis: SequenceAnalysis::new(best_sequence)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+
+    /// Returns the options of a reproducible search with the given objective.
+    fn options(objective: Objective) -> OptimizerOptions {
+        OptimizerOptions {
+            objective,
+            ops_weight: 2.0,
+            line_changes_weight: 0.5,
+            time_budget: Duration::from_secs(600),
+            max_iterations: Some(3000),
+            seed: Some(11),
+            ..Default::default()
+        }
+    }
+
+    /// Verifies that the optimizer improves a sequence, keeps its hexagrams, and reports a cost
+    /// that matches the analysis of the sequence it returns.
+    #[test]
+    fn test_optimize() -> Result<()> {
+        let sequence = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut reports = 0;
+        let result =
+            optimize_sequence(sequence.clone(), &options(Objective::Ops), |_| reports += 1)?;
+        assert!(reports >= 1);
+        assert_eq!(result.iterations, 3000);
+        assert!(result.cost < result.initial_cost);
+        assert_eq!(result.cost, result.analysis.total_ops as f64);
+        let mut hexagrams = result.analysis.sequence.clone();
+        hexagrams.sort();
+        assert_eq!(hexagrams, sequence);
+
+        // The same seed finds the same sequence.
+        let again = optimize_sequence(sequence.clone(), &options(Objective::Ops), |_| {})?;
+        assert_eq!(again.analysis.sequence, result.analysis.sequence);
+        Ok(())
+    }
+
+    /// Verifies the cost of each objective.
+    #[test]
+    fn test_objectives() -> Result<()> {
+        let sequence = vec![1, 2, 11, 12, 63, 64];
+        let result = optimize_sequence(sequence.clone(), &options(Objective::LineChanges), |_| {})?;
+        assert_eq!(result.cost, result.analysis.total_line_changes as f64);
+
+        let result = optimize_sequence(sequence, &options(Objective::Weighted), |_| {})?;
+        let analysis = &result.analysis;
+        assert_eq!(
+            result.cost,
+            2.0 * analysis.total_ops as f64 + 0.5 * analysis.total_line_changes as f64
+        );
+        assert!(optimize_sequence(vec![1], &options(Objective::Ops), |_| {}).is_err());
+        assert!(optimize_sequence(vec![1, 2, 1], &options(Objective::Ops), |_| {}).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+//! Module containing the logic to print the results of the CLI in a structured format.
+//!
+//! Besides the default human-readable text, every result can be printed as JSON or YAML. Both
+//! formats share the same schema, which mirrors the structures of the library:
+//!
+//! - A reading is an object with the fields `question` (string), `present` (hexagram), `future`
+//!   (hexagram or null), `changing_lines` (sorted list of zero-based line indices counting from the
+//!   bottom), `throws` (value of each line from 6 to 9), `casting` (optional, see below),
+//!   `fallback` (optional, why a fallback source of randomness was used), `signatures` (optional,
+//!   see below), `translation` (name of the text pack), and `texts` (list of applicable texts).
+//! - A casting is an object with the field `method`. For `coin`, the field `tosses` contains the
+//!   three tosses of each line, each worth 2 or 3. For `yarrow-stalks`, the field `splits` contains
+//!   the three splits of each line, each an object with the fields `stalks`, `left`, `right`,
+//!   `left_remainder`, `right_remainder`, and `groups`. For `sixteen-marbles`, the field `marbles`
+//!   contains the marble drawn for each line, from 1 to 16. For `two-coin`, the field `tosses`
+//!   contains the four tosses of each line. For `dice`, the fields `lower`, `upper`, and `line`
+//!   contain the rolls of the dice. For `plum-blossom`, the fields `year`, `month`, `day`, and
+//!   `hour` contain the numbers of the time of the reading in the lunar calendar. For
+//!   `plum-blossom-numbers`, the fields `first` and `second` contain the numbers given by the
+//!   person asking.
+//! - Each signature is an object with the fields `serial_number` (number), `random` (the signed
+//!   JSON object returned by random.org, as a string), and `signature` (base64 string).
+//! - An applicable text has the fields `reference`, `primary` (boolean), and `text` (string, or
+//!   null if the text pack does not contain the text). The reference is an object with the field
+//!   `kind` (`judgment`, `line`, or `all_lines`), the field `hexagram` (number), and, for lines,
+//!   the field `line` (zero-based index).
+//! - A hexagram is an object with the fields `number`, `lines` (list of `open` or `closed`, from
+//!   bottom to top), `name`, `chinese_name`, `english_names` (list of strings), and `symbol`. A
+//!   trigram has the same fields, with three lines instead of six.
+//! - A path is a list of steps. Each step has the fields `hexagram` and `operation`. An operation
+//!   is an object with the field `kind`, the name of the operation in snake case, and, for the
+//!   `inverse_line` operation, the field `line` (`first` to `sixth`). The operation of the first
+//!   step of a path is always `no_op`.
+//! - A hexagram analysis has the fields `hexagram`, `bottom_trigram`, `top_trigram`,
+//!   `bottom_nuclear_trigram`, `top_nuclear_trigram`, and `reacheable_hexagrams`, the last being
+//!   a list of steps.
+//! - The result of a shortest path search has the fields `start`, `end`, `num_paths` (the number
+//!   of shortest paths found), and `paths` (the paths listed, which may be fewer with `--limit`).
+//! - A sequence analysis has the fields `sequence` (list of numbers), `shortest_paths` (for each
+//!   consecutive pair of hexagrams, the list of shortest paths between them), `total_ops`,
+//!   `total_line_changes`, `total_paths`, and `pairs`. The pairs are an object with the fields
+//!   `relations` (`reverse`, `inverse`, `reverse_and_inverse`, or `unrelated` for each pair),
+//!   `reverse_pairs`, `inverse_pairs`, `reverse_and_inverse_pairs`, `unrelated_pairs`,
+//!   `within_pairs`, and `between_pairs`, the last two being the number of pairs differing by 0 to
+//!   6 lines.
+//! - A comparison of sequences has the fields `reference` and `other`, both sequence analyses. A
+//!   comparison of several sequences has the fields `names` and `analyses`, in the same order.
+//! - A significance test has the fields `sequence`, `num_sequences`, `constraint` (`none` or
+//!   `pairs`), and `distributions`. Each distribution has the fields `metric` (the name of the
+//!   metric in snake case), `reference`, `mean`, `std_dev`, `min`, `max`, `percentiles` (list of
+//!   pairs of a percentile and its value), `p_value_lower`, `p_value_upper`, and `histogram` (list
+//!   of bins with the fields `start`, `end`, and `count`).
+//! - An optimization has the fields `objective` (`ops`, `line-changes`, or `weighted`),
+//!   `initial_cost`, `cost`, `iterations`, `elapsed_secs`, and `analysis`, the sequence analysis of
+//!   the best sequence found.
+//! - A distance matrix has the fields `ops`, `line_changes`, and `paths`, each a list with a row
+//!   for each start hexagram containing the value for each end hexagram.
+//! - The verification of a reading has the fields `id` and `serial_numbers`, the serial numbers of
+//!   the signed batches used to cast it.
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Display;
+
+use crate::{
+    iching::{Reading, TextReference},
+    text_pack::TextPack,
+};
+
+/// The format used to print the results.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+
+    /// JSON, printed as a single document.
+    Json,
+
+    /// YAML, printed as a single document.
+    Yaml,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+/// Serializes the value into a string in the given format. The text format is not a serialization
+/// format, so it is rejected.
+pub fn to_string<T: Serialize>(value: &T, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Text => bail!("the {} format cannot be used to serialize values", format),
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(value)?),
+    }
+}
+
+/// Prints the value in the given format. The given function is used to print the value in the text
+/// format.
+pub fn print<T: Serialize>(value: &T, format: OutputFormat, print_text: impl Fn(&T)) -> Result<()> {
+    match format {
+        OutputFormat::Text => print_text(value),
+        OutputFormat::Json => println!("{}", to_string(value, format)?),
+        OutputFormat::Yaml => print!("{}", to_string(value, format)?),
+    }
+    Ok(())
+}
+
+/// A text that applies to a reading, along with its contents.
+#[derive(Clone, Debug, Serialize)]
+pub struct ApplicableTextOutput {
+    /// The text to consult.
+    pub reference: TextReference,
+
+    /// Whether this text is the primary one.
+    pub primary: bool,
+
+    /// The contents of the text, or `None` if the text pack does not contain it.
+    pub text: Option<String>,
+}
+
+/// A reading along with the texts that apply to it.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReadingOutput {
+    /// The reading itself.
+    #[serde(flatten)]
+    pub reading: Reading,
+
+    /// The name of the text pack used to look up the texts.
+    pub translation: String,
+
+    /// The texts that apply to the reading.
+    pub texts: Vec<ApplicableTextOutput>,
+}
+
+impl ReadingOutput {
+    /// Creates the output of the reading using the texts from the given text pack.
+    pub fn new(reading: Reading, texts: &TextPack) -> Self {
+        let applicable = reading
+            .applicable_texts()
+            .into_iter()
+            .map(|text| ApplicableTextOutput {
+                reference: text.reference,
+                primary: text.primary,
+                text: texts.text(&text.reference).map(str::to_string),
+            })
+            .collect();
+        Self {
+            reading,
+            translation: texts.name.clone(),
+            texts: applicable,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        iching::reading_from_throws, iching_analyzer::HexagramAnalysis,
+        text_pack::BUILTIN_TEXT_PACK,
+    };
+
+    /// Verifies the schema of a serialized reading.
+    #[test]
+    fn test_reading_json() -> Result<()> {
+        let reading = reading_from_throws(&[7, 7, 9, 8, 8, 6], "Will it work?")?;
+        let output = ReadingOutput::new(reading, &BUILTIN_TEXT_PACK);
+        let value: serde_json::Value =
+            serde_json::from_str(&to_string(&output, OutputFormat::Json)?)?;
+
+        assert_eq!(value["question"], "Will it work?");
+        assert_eq!(value["present"]["number"], 11);
+        assert_eq!(value["present"]["symbol"], "䷊");
+        assert_eq!(value["present"]["lines"][0], "closed");
+        assert_eq!(value["future"]["number"], 41);
+        assert_eq!(value["changing_lines"], serde_json::json!([2, 5]));
+        assert_eq!(value["translation"], "legge");
+        assert_eq!(value["texts"][0]["reference"]["kind"], "line");
+        assert_eq!(value["texts"][0]["reference"]["line"], 5);
+        assert_eq!(value["texts"][0]["primary"], true);
+        assert_eq!(value["texts"][1]["primary"], false);
+        assert!(value["texts"][0]["text"].is_string());
+
+        // Texts missing from the pack are null rather than empty.
+        let mut pack = BUILTIN_TEXT_PACK.clone();
+        pack.hexagrams[10].lines.truncate(3);
+        let output = ReadingOutput::new(output.reading, &pack);
+        let value: serde_json::Value =
+            serde_json::from_str(&to_string(&output, OutputFormat::Json)?)?;
+        assert!(value["texts"][0]["text"].is_null());
+        assert!(value["texts"][1]["text"].is_string());
+
+        assert!(to_string(&output, OutputFormat::Text).is_err());
+        Ok(())
+    }
+
+    /// Verifies that the YAML output follows the same schema as the JSON output.
+    #[test]
+    fn test_analysis_yaml() -> Result<()> {
+        let analysis = HexagramAnalysis::new(1)?;
+        let yaml: serde_yaml::Value =
+            serde_yaml::from_str(&to_string(&analysis, OutputFormat::Yaml)?)?;
+        let json: serde_yaml::Value =
+            serde_json::from_str(&to_string(&analysis, OutputFormat::Json)?)?;
+        assert_eq!(yaml, json);
+        assert_eq!(yaml["bottom_trigram"]["name"], "Qian");
+        let operation = &yaml["reacheable_hexagrams"][0]["operation"];
+        assert_eq!(operation["kind"], "inverse_line");
+        assert_eq!(operation["line"], "first");
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Module containing the calendar logic of the plum blossom (Mei Hua Yi Shu) method.
+//!
+//! Instead of drawing random numbers, the plum blossom method derives a reading either from the
+//! time at which it is cast or from numbers supplied by the person asking. The time is counted in
+//! the Chinese lunisolar calendar: the year and the double hour by their earthly branches, and the
+//! month and the day by their lunar numbers. The arithmetic on those numbers lives with the rest of
+//! the casting logic in the [`iching`](crate::iching) module.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, Local, NaiveDateTime, Timelike};
+use icu_calendar::{cal::ChineseTraditional, Date};
+
+use crate::iching::Casting;
+
+/// The names of the earthly branches, in order.
+const EARTHLY_BRANCHES: [&str; 12] = [
+    "Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai",
+];
+
+/// A time in the Chinese lunisolar calendar, as used by the plum blossom method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LunarTime {
+    /// The earthly branch of the year, from 1 (Zi) to 12 (Hai).
+    pub year: u8,
+
+    /// The lunar month, from 1 to 12. A leap month has the number of the month it repeats.
+    pub month: u8,
+
+    /// The day of the lunar month, from 1 to 30.
+    pub day: u8,
+
+    /// The earthly branch of the double hour, from 1 (Zi, 23:00 to 01:00) to 12 (Hai).
+    pub hour: u8,
+}
+
+impl LunarTime {
+    /// Creates a lunar time from its numbers. Returns an error if any of them is out of range.
+    pub fn new(year: u8, month: u8, day: u8, hour: u8) -> Result<Self> {
+        if !(1..=12).contains(&year) {
+            bail!("invalid earthly branch of the year: {}", year);
+        }
+        if !(1..=12).contains(&month) {
+            bail!("invalid lunar month: {}", month);
+        }
+        if !(1..=30).contains(&day) {
+            bail!("invalid lunar day: {}", day);
+        }
+        if !(1..=12).contains(&hour) {
+            bail!("invalid earthly branch of the hour: {}", hour);
+        }
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+        })
+    }
+
+    /// Converts a date and time of the Gregorian calendar to the lunar calendar. The lunar date
+    /// changes at midnight, even though the double hour of Zi starts at 23:00.
+    pub fn from_datetime(time: &NaiveDateTime) -> Result<Self> {
+        let iso = Date::try_new_iso(time.year(), time.month() as u8, time.day() as u8)
+            .map_err(|error| anyhow!("cannot convert {} to the lunar calendar: {}", time, error))?;
+        let lunar = iso.to_calendar(ChineseTraditional::new());
+
+        // The sexagenary cycle starts with the year Jia Zi, so the branch repeats every 12 years.
+        let cycle_year = lunar.cyclic_year().year;
+        Self::new(
+            (cycle_year - 1) % 12 + 1,
+            lunar.month().number(),
+            lunar.day_of_month().0,
+            hour_branch(time.hour()),
+        )
+    }
+
+    /// Returns the current local time in the lunar calendar.
+    pub fn now() -> Result<Self> {
+        Self::from_datetime(&Local::now().naive_local())
+    }
+
+    /// Returns the plum blossom casting for this time.
+    pub fn casting(&self) -> Casting {
+        Casting::PlumBlossom {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            hour: self.hour,
+        }
+    }
+}
+
+/// Returns the earthly branch, from 1 (Zi) to 12 (Hai), of the double hour containing the given
+/// hour of the day. The first double hour starts at 23:00.
+pub fn hour_branch(hour: u32) -> u8 {
+    (hour.div_ceil(2) % 12 + 1) as u8
+}
+
+/// Returns the name of the earthly branch with the given number, from 1 to 12.
+pub fn branch_name(branch: u8) -> Option<&'static str> {
+    EARTHLY_BRANCHES
+        .get(branch.checked_sub(1)? as usize)
+        .copied()
+}
+
+/// Returns the plum blossom casting for two numbers supplied by the person asking, such as the
+/// number of strokes of two characters or two numbers that came to mind. Both numbers must be
+/// positive.
+pub fn numbers_casting(first: u32, second: u32) -> Result<Casting> {
+    if first == 0 || second == 0 {
+        bail!("the numbers of the plum blossom method must be positive");
+    }
+    Ok(Casting::PlumBlossomNumbers { first, second })
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::iching::reading_from_casting;
+
+    /// Returns the given date and time.
+    fn datetime(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 30, 0)
+            .unwrap()
+    }
+
+    /// Verifies the conversion of dates and times to the lunar calendar.
+    #[test]
+    fn test_lunar_time() -> Result<()> {
+        // The last day of the year of the dragon and the first day of the year of the snake.
+        assert_eq!(
+            LunarTime::from_datetime(&datetime(2025, 1, 28, 0))?,
+            LunarTime::new(5, 12, 29, 1)?
+        );
+        assert_eq!(
+            LunarTime::from_datetime(&datetime(2025, 1, 29, 23))?,
+            LunarTime::new(6, 1, 1, 1)?
+        );
+
+        // The leap second month of 2023 counts as the second month.
+        assert_eq!(
+            LunarTime::from_datetime(&datetime(2023, 3, 22, 12))?,
+            LunarTime::new(4, 2, 1, 7)?
+        );
+
+        assert_eq!(hour_branch(0), 1);
+        assert_eq!(hour_branch(1), 2);
+        assert_eq!(hour_branch(22), 12);
+        assert_eq!(branch_name(5), Some("Chen"));
+        assert_eq!(branch_name(0), None);
+        assert!(LunarTime::new(1, 1, 31, 1).is_err());
+        Ok(())
+    }
+
+    /// Verifies the classic example of the plum blossom method: the year of the dragon, twelfth
+    /// month, seventeenth day, at the hour of the monkey gives Ge, with the bottom line changing
+    /// into Xian.
+    #[test]
+    fn test_plum_blossom_example() -> Result<()> {
+        let time = LunarTime::from_datetime(&datetime(2025, 1, 16, 15))?;
+        assert_eq!(time, LunarTime::new(5, 12, 17, 9)?);
+        let reading = reading_from_casting(time.casting(), "")?;
+        assert_eq!(reading.present().number, 49);
+        assert_eq!(reading.future().unwrap().number, 31);
+        assert_eq!(reading.changing_lines().len(), 1);
+        assert!(reading.changing_lines().contains(&0));
+        Ok(())
+    }
+
+    /// Verifies casting a reading from two numbers.
+    #[test]
+    fn test_numbers() -> Result<()> {
+        // 11 gives Li above, 13 gives Xun below, and 24 changes the top line: Ding changing into
+        // Heng.
+        let reading = reading_from_casting(numbers_casting(11, 13)?, "")?;
+        assert_eq!(reading.present().number, 50);
+        assert_eq!(reading.future().unwrap().number, 32);
+        assert_eq!(reading.changing_lines().len(), 1);
+        assert!(numbers_casting(0, 3).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Module containing the orderings of the hexagrams that can be analyzed as sequences.
+//!
+//! Besides King Wen's sequence, the order of the received text, the I Ching tradition knows several
+//! other orderings of the 64 hexagrams. The Fu Xi sequence orders them as binary numbers, the
+//! Mawangdui sequence found in the silk manuscripts groups them by their upper trigram, and the
+//! Eight Palaces of Jing Fang derive each group from a doubled trigram by changing one line at a
+//! time. Any other ordering can be loaded from a file.
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt::Display, fs, path::Path};
+
+use crate::{
+    iching::{create_hexagram, Hexagram, Line, Trigram, HEXAGRAMS, HEXAGRAM_INDEX},
+    iching_analyzer::king_wen,
+};
+
+/// The upper trigrams of the eight groups of the Mawangdui sequence, as numbers in the table of
+/// trigrams: Qian, Gen, Kan, Zhen, Kun, Dui, Li, and Xun.
+const MAWANGDUI_UPPER_TRIGRAMS: [u8; 8] = [1, 4, 3, 2, 5, 8, 7, 6];
+
+/// The order of the lower trigrams within each group of the Mawangdui sequence, as numbers in the
+/// table of trigrams: Qian, Kun, Gen, Dui, Kan, Li, Zhen, and Xun. Each group starts instead with
+/// the lower trigram equal to the upper one, which is skipped when its turn comes.
+const MAWANGDUI_LOWER_TRIGRAMS: [u8; 8] = [1, 5, 4, 8, 3, 7, 2, 6];
+
+/// The trigrams heading the Eight Palaces, as numbers in the table of trigrams: Qian, Zhen, Kan,
+/// Gen, Kun, Xun, Li, and Dui.
+const PALACE_TRIGRAMS: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+/// A traditional ordering of the 64 hexagrams.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Sequence {
+    /// King Wen's sequence, the order of the hexagrams in the received text of the I Ching.
+    #[default]
+    KingWen,
+
+    /// The Fu Xi sequence, also called the binary or Shao Yong sequence. Reading each hexagram as a
+    /// binary number, with closed lines as ones and the bottom line as the most significant digit,
+    /// it counts down from Qian (63) to Kun (0).
+    FuXi,
+
+    /// The sequence of the Mawangdui silk manuscripts. It forms eight groups of eight hexagrams
+    /// sharing the same upper trigram. Each group starts with the doubled trigram and follows with
+    /// the remaining lower trigrams in a fixed order.
+    Mawangdui,
+
+    /// The Eight Palaces of Jing Fang. Each palace starts with a doubled trigram, changes its lines
+    /// from the bottom up to the fifth one, then restores the fourth line (the wandering soul) and
+    /// finally the lower trigram (the returning soul).
+    EightPalaces,
+}
+
+impl Sequence {
+    /// Returns the numbers of the hexagrams in this ordering.
+    pub fn hexagrams(&self) -> Result<Vec<usize>> {
+        match self {
+            Sequence::KingWen => Ok(king_wen()),
+            Sequence::FuXi => Ok(fu_xi()),
+            Sequence::Mawangdui => mawangdui(),
+            Sequence::EightPalaces => eight_palaces(),
+        }
+    }
+}
+
+impl Display for Sequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sequence::KingWen => write!(f, "king-wen"),
+            Sequence::FuXi => write!(f, "fu-xi"),
+            Sequence::Mawangdui => write!(f, "mawangdui"),
+            Sequence::EightPalaces => write!(f, "eight-palaces"),
+        }
+    }
+}
+
+/// Returns the value of the hexagram as a binary number, with closed lines as ones and the bottom
+/// line as the most significant digit.
+fn binary_value(hexagram: &Hexagram) -> u8 {
+    hexagram
+        .lines
+        .iter()
+        .fold(0, |value, line| value * 2 + (*line == Line::Closed) as u8)
+}
+
+/// Returns the Fu Xi sequence.
+fn fu_xi() -> Vec<usize> {
+    let mut hexagrams: Vec<Hexagram> = HEXAGRAMS
+        .iter()
+        .map(|(number, lines, ..)| create_hexagram(*number, *lines))
+        .collect();
+    hexagrams.sort_by_key(|hexagram| std::cmp::Reverse(binary_value(hexagram)));
+    hexagrams
+        .iter()
+        .map(|hexagram| hexagram.number as usize)
+        .collect()
+}
+
+/// Returns the Mawangdui sequence.
+fn mawangdui() -> Result<Vec<usize>> {
+    let mut sequence = Vec::with_capacity(64);
+    for upper_number in MAWANGDUI_UPPER_TRIGRAMS {
+        let upper = Trigram::from_number(upper_number)?;
+        sequence.push(Hexagram::from_trigrams(&upper, &upper).number as usize);
+        for lower_number in MAWANGDUI_LOWER_TRIGRAMS {
+            if lower_number != upper_number {
+                let lower = Trigram::from_number(lower_number)?;
+                sequence.push(Hexagram::from_trigrams(&lower, &upper).number as usize);
+            }
+        }
+    }
+    Ok(sequence)
+}
+
+/// Returns the sequence of the Eight Palaces.
+fn eight_palaces() -> Result<Vec<usize>> {
+    let mut sequence = Vec::with_capacity(64);
+    for palace_number in PALACE_TRIGRAMS {
+        let palace = Trigram::from_number(palace_number)?;
+        let mut lines = [Line::Open; 6];
+        lines[..3].copy_from_slice(&palace.lines);
+        lines[3..].copy_from_slice(&palace.lines);
+        sequence.push(HEXAGRAM_INDEX[&lines].number as usize);
+
+        // Change the lines one at a time from the bottom up to the fifth one.
+        for i in 0..5 {
+            lines[i] = lines[i].inverse();
+            sequence.push(HEXAGRAM_INDEX[&lines].number as usize);
+        }
+
+        // The wandering soul restores the fourth line, and the returning soul the lower trigram.
+        lines[3] = lines[3].inverse();
+        sequence.push(HEXAGRAM_INDEX[&lines].number as usize);
+        lines[..3].copy_from_slice(&palace.lines);
+        sequence.push(HEXAGRAM_INDEX[&lines].number as usize);
+    }
+    Ok(sequence)
+}
+
+/// Loads a sequence of hexagrams from a file with one hexagram per line, in any of the forms
+/// accepted by [`Hexagram::from_str`](std::str::FromStr::from_str), such as "63", "Ji Ji", or
+/// "Kan/Li". Empty lines and lines starting with `#` are ignored. The sequence must contain at
+/// least two hexagrams and no hexagram more than once.
+pub fn load_sequence(path: &Path) -> Result<Vec<usize>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("cannot read sequence file {}", path.display()))?;
+
+    let mut sequence = vec![];
+    let mut seen = HashSet::new();
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let hexagram: Hexagram = line
+            .parse()
+            .with_context(|| format!("invalid hexagram on line {} of {}", i + 1, path.display()))?;
+        if !seen.insert(hexagram.number) {
+            bail!(
+                "hexagram {} appears more than once in {}",
+                hexagram.number,
+                path.display()
+            );
+        }
+        sequence.push(hexagram.number as usize);
+    }
+    if sequence.len() < 2 {
+        bail!(
+            "the sequence in {} must contain at least two hexagrams",
+            path.display()
+        );
+    }
+    Ok(sequence)
+}
+
+/// Returns the hexagrams of the traditional ordering with the given name, such as "fu-xi", or
+/// loads them from the file at the given path if it is not the name of an ordering.
+pub fn resolve_sequence(name_or_path: &str) -> Result<Vec<usize>> {
+    match Sequence::from_str(name_or_path, true) {
+        Ok(sequence) => sequence.hexagrams(),
+        Err(_) => load_sequence(Path::new(name_or_path)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use std::io::Write;
+
+    use super::*;
+
+    /// Verifies that every traditional ordering contains each hexagram exactly once.
+    #[test]
+    fn test_complete_sequences() -> Result<()> {
+        for sequence in Sequence::value_variants() {
+            let mut hexagrams = sequence.hexagrams()?;
+            hexagrams.sort();
+            assert_eq!(hexagrams, king_wen(), "{}", sequence);
+        }
+        Ok(())
+    }
+
+    /// Verifies the Fu Xi sequence.
+    #[test]
+    fn test_fu_xi_sequence() -> Result<()> {
+        let sequence = Sequence::FuXi.hexagrams()?;
+        assert_eq!(sequence[..8], [1, 43, 14, 34, 9, 5, 26, 11]);
+        assert_eq!(sequence[56..], [12, 45, 35, 16, 20, 8, 23, 2]);
+        Ok(())
+    }
+
+    /// Verifies the Mawangdui sequence.
+    #[test]
+    fn test_mawangdui_sequence() -> Result<()> {
+        assert_eq!(
+            Sequence::Mawangdui.hexagrams()?,
+            [
+                1, 12, 33, 10, 6, 13, 25, 44, 52, 26, 23, 41, 4, 22, 27, 18, 29, 5, 8, 39, 60, 63,
+                3, 48, 51, 34, 16, 62, 54, 40, 55, 32, 2, 11, 15, 19, 7, 36, 24, 46, 58, 43, 45,
+                31, 47, 49, 17, 28, 30, 14, 35, 56, 38, 64, 21, 50, 57, 9, 20, 53, 61, 59, 37, 42,
+            ]
+        );
+        Ok(())
+    }
+
+    /// Verifies the sequence of the Eight Palaces.
+    #[test]
+    fn test_eight_palaces_sequence() -> Result<()> {
+        assert_eq!(
+            Sequence::EightPalaces.hexagrams()?,
+            [
+                1, 44, 33, 12, 20, 23, 35, 14, 51, 16, 40, 32, 46, 48, 28, 17, 29, 60, 3, 63, 49,
+                55, 36, 7, 52, 22, 26, 41, 38, 10, 61, 53, 2, 24, 19, 11, 34, 43, 5, 8, 57, 9, 37,
+                42, 25, 21, 27, 18, 30, 56, 50, 64, 4, 59, 6, 13, 58, 47, 45, 31, 39, 15, 62, 54,
+            ]
+        );
+        Ok(())
+    }
+
+    /// Verifies loading a sequence from a file.
+    #[test]
+    fn test_load_from_file() -> Result<()> {
+        let mut file = tempfile::NamedTempFile::new()?;
+        writeln!(file, "# The last two hexagrams.\n63\n\nWei Ji\n")?;
+        assert_eq!(load_sequence(file.path())?, [63, 64]);
+
+        writeln!(file, "Kan/Li")?;
+        let error = load_sequence(file.path()).unwrap_err().to_string();
+        assert!(error.contains("more than once"), "{}", error);
+
+        let mut file = tempfile::NamedTempFile::new()?;
+        writeln!(file, "1\nfoo")?;
+        let error = load_sequence(file.path()).unwrap_err().to_string();
+        assert!(error.contains("line 2"), "{}", error);
+
+        let mut file = tempfile::NamedTempFile::new()?;
+        writeln!(file, "1")?;
+        assert!(load_sequence(file.path()).is_err());
+        Ok(())
+    }
+
+    /// Verifies that sequences are resolved by name first and by path otherwise.
+    #[test]
+    fn test_resolve() -> Result<()> {
+        assert_eq!(resolve_sequence("fu-xi")?, Sequence::FuXi.hexagrams()?);
+        assert_eq!(resolve_sequence("Eight-Palaces")?[..2], [1, 44]);
+
+        let mut file = tempfile::NamedTempFile::new()?;
+        writeln!(file, "2\n1")?;
+        assert_eq!(resolve_sequence(file.path().to_str().unwrap())?, [2, 1]);
+        assert!(resolve_sequence("no-such-sequence").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+//! Module containing the Monte Carlo tests of the significance of the properties of a sequence.
+//!
+//! A property of a sequence, such as its total number of operations, is only remarkable if random
+//! sequences rarely match it. The test analyzes many random shuffles of the sequence, optionally
+//! keeping its pairs together, and reports the distribution of each metric along with the
+//! empirical p-value of the score of the sequence under test.
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use rand::{seq::SliceRandom, Rng};
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::{iching::seeded_rng, iching_analyzer::SequenceAnalysis};
+
+/// The percentiles reported for each metric.
+const PERCENTILES: [u8; 7] = [1, 5, 25, 50, 75, 95, 99];
+
+/// The width in characters of the longest bar of a histogram.
+const HISTOGRAM_WIDTH: usize = 50;
+
+/// The constraint that random sequences must respect.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ShuffleConstraint {
+    /// Shuffle the hexagrams freely.
+    #[default]
+    None,
+
+    /// Keep the pairs of the sequence, the hexagrams at positions 1 and 2, 3 and 4, and so on,
+    /// together and in order, and only shuffle the order of the pairs. If the sequence has an odd
+    /// length, its last hexagram stays in place.
+    Pairs,
+}
+
+impl ShuffleConstraint {
+    /// Returns a random shuffle of the sequence that respects this constraint.
+    fn shuffle<R: Rng>(&self, sequence: &[usize], rng: &mut R) -> Vec<usize> {
+        match self {
+            ShuffleConstraint::None => {
+                let mut shuffled = sequence.to_vec();
+                shuffled.shuffle(rng);
+                shuffled
+            }
+            ShuffleConstraint::Pairs => {
+                let chunks = sequence.chunks_exact(2);
+                let remainder = chunks.remainder();
+                let mut pairs: Vec<&[usize]> = chunks.collect();
+                pairs.shuffle(rng);
+                pairs
+                    .concat()
+                    .into_iter()
+                    .chain(remainder.to_vec())
+                    .collect()
+            }
+        }
+    }
+}
+
+impl Display for ShuffleConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShuffleConstraint::None => write!(f, "none"),
+            ShuffleConstraint::Pairs => write!(f, "pairs"),
+        }
+    }
+}
+
+/// A metric of a sequence whose significance is tested.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    /// The total number of operations between consecutive hexagrams.
+    TotalOps,
+
+    /// The total number of line changes between consecutive hexagrams.
+    TotalLineChanges,
+
+    /// The number of lines changed per operation.
+    LinesPerOperation,
+
+    /// The number of pairs related by reversal, inversion, or both.
+    RelatedPairs,
+
+    /// The number of consecutive pairs that differ by 5 lines.
+    FiveLineTransitions,
+}
+
+impl Metric {
+    /// Returns all the metrics, in the order in which they are reported.
+    pub fn all() -> [Metric; 5] {
+        [
+            Metric::TotalOps,
+            Metric::TotalLineChanges,
+            Metric::LinesPerOperation,
+            Metric::RelatedPairs,
+            Metric::FiveLineTransitions,
+        ]
+    }
+
+    /// Returns the value of the metric for the given analysis.
+    pub fn value(&self, analysis: &SequenceAnalysis) -> f64 {
+        match self {
+            Metric::TotalOps => analysis.total_ops as f64,
+            Metric::TotalLineChanges => analysis.total_line_changes as f64,
+            Metric::LinesPerOperation => {
+                analysis.total_line_changes as f64 / analysis.total_ops as f64
+            }
+            Metric::RelatedPairs => {
+                (analysis.pairs.relations.len() - analysis.pairs.unrelated_pairs) as f64
+            }
+            Metric::FiveLineTransitions => analysis.pairs.between_pairs[5] as f64,
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Metric::TotalOps => write!(f, "Total operations"),
+            Metric::TotalLineChanges => write!(f, "Total line changes"),
+            Metric::LinesPerOperation => write!(f, "Lines changed per operation"),
+            Metric::RelatedPairs => write!(f, "Pairs related by reversal or inversion"),
+            Metric::FiveLineTransitions => write!(f, "Consecutive pairs differing by 5 lines"),
+        }
+    }
+}
+
+/// A bin of a histogram, covering the values from `start` up to `end`. The last bin also includes
+/// `end`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HistogramBin {
+    /// The lowest value in the bin.
+    pub start: f64,
+
+    /// The value at which the next bin starts.
+    pub end: f64,
+
+    /// The number of random sequences whose value falls in the bin.
+    pub count: usize,
+}
+
+/// The distribution of a metric over the random sequences, compared to the value of the sequence
+/// under test.
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricDistribution {
+    /// The metric.
+    pub metric: Metric,
+
+    /// The value of the metric for the sequence under test.
+    pub reference: f64,
+
+    /// The mean of the metric over the random sequences.
+    pub mean: f64,
+
+    /// The sample standard deviation of the metric over the random sequences.
+    pub std_dev: f64,
+
+    /// The lowest value of the metric over the random sequences.
+    pub min: f64,
+
+    /// The highest value of the metric over the random sequences.
+    pub max: f64,
+
+    /// The percentiles of the metric over the random sequences, as pairs of the percentile and its
+    /// value.
+    pub percentiles: Vec<(u8, f64)>,
+
+    /// The empirical probability that a random sequence scores as low as the sequence under test
+    /// or lower. One is added to both the count and the number of sequences, so the p-value is
+    /// never zero.
+    pub p_value_lower: f64,
+
+    /// The empirical probability that a random sequence scores as high as the sequence under test
+    /// or higher, computed in the same way as the lower p-value.
+    pub p_value_upper: f64,
+
+    /// The histogram of the metric over the random sequences.
+    pub histogram: Vec<HistogramBin>,
+}
+
+impl MetricDistribution {
+    /// Computes the distribution of the metric from its values over the random sequences. Returns
+    /// an error if there are no values.
+    pub fn new(
+        metric: Metric,
+        reference: f64,
+        mut values: Vec<f64>,
+        num_bins: usize,
+    ) -> Result<Self> {
+        if values.is_empty() {
+            bail!(
+                "cannot compute the distribution of {:?} without values",
+                metric
+            );
+        }
+        values.sort_by(f64::total_cmp);
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                // Use the nearest-rank method.
+                let rank = (p as f64 / 100.0 * n).ceil() as usize;
+                (p, values[rank.clamp(1, values.len()) - 1])
+            })
+            .collect();
+        let lower = values.iter().filter(|v| **v <= reference).count();
+        let upper = values.iter().filter(|v| **v >= reference).count();
+
+        Ok(Self {
+            metric,
+            reference,
+            mean,
+            std_dev: variance.sqrt(),
+            min: values[0],
+            max: values[values.len() - 1],
+            percentiles,
+            p_value_lower: (lower + 1) as f64 / (n + 1.0),
+            p_value_upper: (upper + 1) as f64 / (n + 1.0),
+            histogram: histogram(&values, reference, num_bins),
+        })
+    }
+
+    /// Prints the distribution and its histogram.
+    fn print(&self) {
+        println!(">>> {}", self.metric);
+        println!();
+        println!("Sequence under test: {:.3}", self.reference);
+        println!(
+            "Random sequences: mean {:.3}, standard deviation {:.3}, min {:.3}, max {:.3}",
+            self.mean, self.std_dev, self.min, self.max
+        );
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(p, value)| format!("{}%: {:.3}", p, value))
+            .collect();
+        println!("Percentiles: {}", percentiles.join(", "));
+        println!(
+            "p-value: {:.4} (as low or lower), {:.4} (as high or higher)",
+            self.p_value_lower, self.p_value_upper
+        );
+        println!();
+
+        // Mark the bin containing the sequence under test.
+        let max_count = self
+            .histogram
+            .iter()
+            .map(|bin| bin.count)
+            .max()
+            .unwrap_or(0);
+        let reference_bin = self
+            .histogram
+            .iter()
+            .rposition(|bin| bin.start <= self.reference);
+        for (i, bin) in self.histogram.iter().enumerate() {
+            let width = (bin.count * HISTOGRAM_WIDTH).div_ceil(max_count.max(1));
+            let marker = if Some(i) == reference_bin { " <" } else { "" };
+            println!(
+                "{:>10.3} - {:<10.3} | {:<HISTOGRAM_WIDTH$} {}{}",
+                bin.start,
+                bin.end,
+                "#".repeat(width),
+                bin.count,
+                marker
+            );
+        }
+        println!();
+    }
+}
+
+/// Returns a histogram of the sorted values with the given number of bins of equal width. The bins
+/// span the range of the values and of the reference, so that the reference falls in one of them.
+fn histogram(values: &[f64], reference: f64, num_bins: usize) -> Vec<HistogramBin> {
+    let start = values[0].min(reference);
+    let end = values[values.len() - 1].max(reference);
+    let num_bins = if start == end { 1 } else { num_bins.max(1) };
+    let width = (end - start) / num_bins as f64;
+
+    let mut bins: Vec<HistogramBin> = (0..num_bins)
+        .map(|i| HistogramBin {
+            start: start + i as f64 * width,
+            end: if i + 1 == num_bins {
+                end
+            } else {
+                start + (i + 1) as f64 * width
+            },
+            count: 0,
+        })
+        .collect();
+    for value in values {
+        let index = if width == 0.0 {
+            0
+        } else {
+            (((value - start) / width) as usize).min(num_bins - 1)
+        };
+        bins[index].count += 1;
+    }
+    bins
+}
+
+/// The result of a Monte Carlo test of the significance of the metrics of a sequence.
+#[derive(Clone, Debug, Serialize)]
+pub struct SignificanceTest {
+    /// The sequence under test.
+    pub sequence: Vec<usize>,
+
+    /// The number of random sequences generated.
+    pub num_sequences: usize,
+
+    /// The constraint respected by the random sequences.
+    pub constraint: ShuffleConstraint,
+
+    /// The distribution of each metric.
+    pub distributions: Vec<MetricDistribution>,
+}
+
+impl SignificanceTest {
+    /// Analyzes the given number of random shuffles of the sequence and compares the distribution
+    /// of each metric to the value of the sequence. If a seed is given, each shuffle uses its own
+    /// stream of a generator initialized with that seed, so the result does not depend on how the
+    /// shuffles are scheduled across threads.
+    pub fn new(
+        sequence: Vec<usize>,
+        num_sequences: usize,
+        constraint: ShuffleConstraint,
+        seed: Option<u64>,
+        num_bins: usize,
+    ) -> Result<Self> {
+        if num_sequences == 0 {
+            bail!("the number of random sequences must be positive");
+        }
+        let metrics = Metric::all();
+        let reference = SequenceAnalysis::new(sequence.clone())?;
+
+        let samples = (0..num_sequences)
+            .into_par_iter()
+            .map(|i| {
+                let shuffled = match seed {
+                    Some(seed) => {
+                        let mut rng = seeded_rng(seed);
+                        rng.set_stream(i as u64);
+                        constraint.shuffle(&sequence, &mut rng)
+                    }
+                    None => constraint.shuffle(&sequence, &mut rand::thread_rng()),
+                };
+                let analysis = SequenceAnalysis::new(shuffled)?;
+                Ok(metrics.map(|metric| metric.value(&analysis)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let distributions = metrics
+            .iter()
+            .enumerate()
+            .map(|(i, metric)| {
+                let values = samples.iter().map(|sample| sample[i]).collect();
+                MetricDistribution::new(*metric, metric.value(&reference), values, num_bins)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            sequence,
+            num_sequences,
+            constraint,
+            distributions,
+        })
+    }
+
+    /// Prints the distribution of each metric.
+    pub fn print(&self) {
+        println!(">>>>> Significance of the properties of a sequence of hexagrams");
+        println!();
+        println!(">>> Sequence of hexagrams: {:?}", self.sequence);
+        println!(
+            ">>> Random sequences: {} (constraint: {})",
+            self.num_sequences, self.constraint
+        );
+        println!();
+        self.distributions
+            .iter()
+            .for_each(MetricDistribution::print);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::*;
+    use crate::iching_analyzer::king_wen;
+
+    /// Verifies the statistics computed from a known set of values.
+    #[test]
+    fn test_distribution() -> Result<()> {
+        let values = (1..=100).map(f64::from).collect();
+        let distribution = MetricDistribution::new(Metric::TotalOps, 5.0, values, 10)?;
+        assert_eq!(distribution.mean, 50.5);
+        assert!((distribution.std_dev - 29.011).abs() < 0.001);
+        assert_eq!(distribution.min, 1.0);
+        assert_eq!(distribution.max, 100.0);
+        assert_eq!(distribution.percentiles[0], (1, 1.0));
+        assert_eq!(distribution.percentiles[3], (50, 50.0));
+        assert_eq!(distribution.percentiles[6], (99, 99.0));
+        assert_eq!(distribution.p_value_lower, 6.0 / 101.0);
+        assert_eq!(distribution.p_value_upper, 97.0 / 101.0);
+        assert_eq!(distribution.histogram.len(), 10);
+        assert_eq!(distribution.histogram[0].start, 1.0);
+        assert_eq!(distribution.histogram[9].end, 100.0);
+        assert_eq!(
+            distribution
+                .histogram
+                .iter()
+                .map(|bin| bin.count)
+                .sum::<usize>(),
+            100
+        );
+
+        // A constant metric gets a single bin.
+        let distribution = MetricDistribution::new(Metric::TotalOps, 3.0, vec![3.0; 4], 10)?;
+        assert_eq!(distribution.histogram.len(), 1);
+        assert_eq!(distribution.histogram[0].count, 4);
+        assert_eq!(distribution.p_value_lower, 1.0);
+        assert!(MetricDistribution::new(Metric::TotalOps, 3.0, vec![], 10).is_err());
+        Ok(())
+    }
+
+    /// Verifies that shuffles keeping the pairs together keep every pair of King Wen's sequence.
+    #[test]
+    fn test_pair_shuffles() {
+        let mut rng = seeded_rng(3);
+        let shuffled = ShuffleConstraint::Pairs.shuffle(&king_wen(), &mut rng);
+        assert_ne!(shuffled, king_wen());
+        for pair in shuffled.chunks(2) {
+            assert_eq!(pair[0] % 2, 1);
+            assert_eq!(pair[1], pair[0] + 1);
+        }
+
+        let shuffled = ShuffleConstraint::Pairs.shuffle(&[1, 2, 3, 4, 5], &mut rng);
+        assert_eq!(shuffled[4], 5);
+    }
+
+    /// Verifies that the test is reproducible with a seed and that King Wen's pairs are significant
+    /// against free shuffles.
+    #[test]
+    fn test_seeded_test() -> Result<()> {
+        let first = SignificanceTest::new(king_wen(), 8, ShuffleConstraint::None, Some(5), 4)?;
+        let second = SignificanceTest::new(king_wen(), 8, ShuffleConstraint::None, Some(5), 4)?;
+        assert_eq!(first.distributions.len(), Metric::all().len());
+        for (first, second) in first.distributions.iter().zip(&second.distributions) {
+            assert_eq!(first.mean, second.mean);
+            assert_eq!(first.histogram, second.histogram);
+        }
+
+        let pairs = &first.distributions[3];
+        assert_eq!(pairs.metric, Metric::RelatedPairs);
+        assert_eq!(pairs.reference, 32.0);
+        assert_eq!(pairs.p_value_upper, 1.0 / 9.0);
+        assert!(SignificanceTest::new(king_wen(), 0, ShuffleConstraint::None, None, 4).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,362 @@
+//! Module containing the logic to load translations and commentaries of the hexagram texts from
+//! disk.
+//!
+//! A text pack is a single TOML, JSON, or YAML file with the same structure as [`TextPack`]. The
+//! format is detected from the extension of the file. The bundled Legge translation is always
+//! available under the name [`BUILTIN_TRANSLATION`] and never needs to be loaded from disk.
+
+use anyhow::{anyhow, bail, Context, Result};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    iching::{TextReference, HEXAGRAMS},
+    texts::HEXAGRAM_TEXTS,
+};
+
+/// The name of the translation bundled with the crate.
+pub const BUILTIN_TRANSLATION: &str = "legge";
+
+/// The file extensions recognized when searching for a text pack, in order of preference.
+const EXTENSIONS: [&str; 4] = ["toml", "json", "yaml", "yml"];
+
+/// The texts of a single hexagram inside a text pack.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TextPackEntry {
+    /// The number of the hexagram in the King Wen sequence.
+    pub number: u8,
+
+    /// The judgment of the hexagram.
+    pub judgment: String,
+
+    /// The image of the hexagram.
+    pub image: String,
+
+    /// The statements for each line, from bottom to top.
+    pub lines: Vec<String>,
+
+    /// The additional statement used when all six lines are changing. Required for hexagrams 1 and
+    /// 2 and ignored for the rest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_lines: Option<String>,
+}
+
+/// A full set of hexagram texts, such as a translation or a commentary.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TextPack {
+    /// The name of the text pack.
+    pub name: String,
+
+    /// An optional description of the text pack, such as the translator and the source.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// The texts of each hexagram.
+    pub hexagrams: Vec<TextPackEntry>,
+}
+
+lazy_static! {
+    /// The text pack containing the bundled translation.
+    pub static ref BUILTIN_TEXT_PACK: TextPack = TextPack::builtin();
+}
+
+impl TextPack {
+    /// Returns the text pack containing the bundled translation.
+    fn builtin() -> Self {
+        TextPack {
+            name: BUILTIN_TRANSLATION.to_string(),
+            description: "James Legge (1882), bundled with the crate".to_string(),
+            hexagrams: HEXAGRAM_TEXTS
+                .iter()
+                .map(|text| TextPackEntry {
+                    number: text.number,
+                    judgment: text.judgment.to_string(),
+                    image: text.image.to_string(),
+                    lines: text.lines.iter().map(|line| line.to_string()).collect(),
+                    all_lines: text.all_lines.map(|all_lines| all_lines.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Parses a text pack from the given contents. The extension determines the format.
+    pub fn parse(contents: &str, extension: &str) -> Result<Self> {
+        let pack: TextPack = match extension {
+            "toml" => toml::from_str(contents)?,
+            "json" => serde_json::from_str(contents)?,
+            "yaml" | "yml" => serde_yaml::from_str(contents)?,
+            _ => bail!("unsupported text pack format: {}", extension),
+        };
+        pack.validate()?;
+        Ok(pack.sorted())
+    }
+
+    /// Loads and validates the text pack stored in the given file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .ok_or_else(|| anyhow!("cannot determine the format of {}", path.display()))?;
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("cannot read text pack {}", path.display()))?;
+        Self::parse(&contents, extension)
+            .with_context(|| format!("cannot load text pack {}", path.display()))
+    }
+
+    /// Returns the text pack with the given name. The bundled translation is returned for
+    /// [`BUILTIN_TRANSLATION`]. Otherwise, the name is used as a path if it points to an existing
+    /// file, or the file `<name>.<extension>` is searched for in the given directory.
+    pub fn find(name: &str, dir: Option<&Path>) -> Result<Self> {
+        if name == BUILTIN_TRANSLATION {
+            return Ok(BUILTIN_TEXT_PACK.clone());
+        }
+
+        let path = Path::new(name);
+        if path.is_file() {
+            return Self::load(path);
+        }
+
+        let dir = match dir {
+            Some(dir) => dir,
+            None => bail!(
+                "translation {} not found and no directory with text packs was given",
+                name
+            ),
+        };
+        let candidates: Vec<PathBuf> = EXTENSIONS
+            .iter()
+            .map(|extension| dir.join(format!("{}.{}", name, extension)))
+            .collect();
+        match candidates.iter().find(|candidate| candidate.is_file()) {
+            Some(candidate) => Self::load(candidate),
+            None => bail!(
+                "translation {} not found in {} (available: {})",
+                name,
+                dir.display(),
+                Self::available(dir)?.join(", ")
+            ),
+        }
+    }
+
+    /// Returns the names of all the text packs available in the given directory, including the
+    /// bundled translation.
+    pub fn available(dir: &Path) -> Result<Vec<String>> {
+        let mut names = vec![BUILTIN_TRANSLATION.to_string()];
+        if dir.is_dir() {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                let extension = path.extension().and_then(|extension| extension.to_str());
+                if !extension.is_some_and(|extension| EXTENSIONS.contains(&extension)) {
+                    continue;
+                }
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// Verifies that the text pack contains a complete entry for each hexagram. All the problems
+    /// are reported in a single error.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+        let mut seen = HashSet::new();
+        for entry in &self.hexagrams {
+            if !(1..=64).contains(&entry.number) {
+                problems.push(format!("invalid hexagram number {}", entry.number));
+                continue;
+            }
+            if !seen.insert(entry.number) {
+                problems.push(format!("hexagram {}: duplicate entry", entry.number));
+                continue;
+            }
+            if entry.judgment.trim().is_empty() {
+                problems.push(format!("hexagram {}: empty judgment", entry.number));
+            }
+            if entry.image.trim().is_empty() {
+                problems.push(format!("hexagram {}: empty image", entry.number));
+            }
+            if entry.lines.len() != 6 {
+                problems.push(format!(
+                    "hexagram {}: expected 6 line statements, found {}",
+                    entry.number,
+                    entry.lines.len()
+                ));
+            }
+            for (i, line) in entry.lines.iter().enumerate() {
+                if line.trim().is_empty() {
+                    problems.push(format!(
+                        "hexagram {}: empty statement for line {}",
+                        entry.number,
+                        i + 1
+                    ));
+                }
+            }
+            let has_all_lines = entry
+                .all_lines
+                .as_ref()
+                .is_some_and(|all_lines| !all_lines.trim().is_empty());
+            if entry.number <= 2 && !has_all_lines {
+                problems.push(format!(
+                    "hexagram {}: missing statement for all lines changing",
+                    entry.number
+                ));
+            }
+        }
+
+        for (number, ..) in HEXAGRAMS.iter() {
+            if !seen.contains(number) {
+                problems.push(format!("hexagram {}: missing entry", number));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            bail!(
+                "text pack {} has {} problem(s):\n  - {}",
+                self.name,
+                problems.len(),
+                problems.join("\n  - ")
+            )
+        }
+    }
+
+    /// Returns the text pack with its entries sorted by hexagram number.
+    fn sorted(mut self) -> Self {
+        let entries: BTreeMap<u8, TextPackEntry> = self
+            .hexagrams
+            .into_iter()
+            .map(|entry| (entry.number, entry))
+            .collect();
+        self.hexagrams = entries.into_values().collect();
+        self
+    }
+
+    /// Returns the text referenced by the given reference, if it exists.
+    pub fn text(&self, reference: &TextReference) -> Option<&str> {
+        match *reference {
+            TextReference::Judgment { hexagram } => {
+                self.hexagram(hexagram).map(|entry| entry.judgment.as_str())
+            }
+            TextReference::Line { hexagram, line } => self
+                .hexagram(hexagram)
+                .and_then(|entry| entry.lines.get(line))
+                .map(|line| line.as_str()),
+            TextReference::AllLines { hexagram } => self
+                .hexagram(hexagram)
+                .and_then(|entry| entry.all_lines.as_deref()),
+        }
+    }
+
+    /// Returns the texts of the hexagram with the given number, or `None` if the pack has no entry
+    /// for it. The entries of the packs loaded from disk are sorted by number, so the entry is
+    /// usually found at its position, but packs built in code are searched if it is not there.
+    pub fn hexagram(&self, number: u8) -> Option<&TextPackEntry> {
+        if !(1..=64).contains(&number) {
+            return None;
+        }
+        self.hexagrams
+            .get(number as usize - 1)
+            .filter(|entry| entry.number == number)
+            .or_else(|| self.hexagrams.iter().find(|entry| entry.number == number))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    use crate::iching::reading_from_throws;
+
+    /// Verifies that the bundled translation is a valid text pack.
+    #[test]
+    fn test_builtin_is_valid() {
+        BUILTIN_TEXT_PACK.validate().unwrap();
+        assert_eq!(BUILTIN_TEXT_PACK.hexagrams.len(), 64);
+        assert_eq!(BUILTIN_TEXT_PACK.hexagram(64).unwrap().number, 64);
+    }
+
+    /// Verifies that a text pack can be round-tripped through every supported format.
+    #[test]
+    fn test_load_all_formats() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut pack = BUILTIN_TEXT_PACK.clone();
+        pack.name = "custom".to_string();
+        pack.hexagrams.reverse();
+        fs::write(dir.path().join("toml-pack.toml"), toml::to_string(&pack)?)?;
+        fs::write(
+            dir.path().join("json-pack.json"),
+            serde_json::to_string(&pack)?,
+        )?;
+        fs::write(
+            dir.path().join("yaml-pack.yaml"),
+            serde_yaml::to_string(&pack)?,
+        )?;
+
+        for name in ["toml-pack", "json-pack", "yaml-pack"] {
+            let loaded = TextPack::find(name, Some(dir.path()))?;
+            assert_eq!(loaded.name, "custom");
+            assert_eq!(loaded.hexagrams, BUILTIN_TEXT_PACK.hexagrams);
+        }
+        assert_eq!(
+            TextPack::available(dir.path())?,
+            vec!["json-pack", "legge", "toml-pack", "yaml-pack"]
+        );
+        Ok(())
+    }
+
+    /// Verifies that every problem in an incomplete text pack is reported.
+    #[test]
+    fn test_incomplete_pack() {
+        let mut pack = BUILTIN_TEXT_PACK.clone();
+        pack.hexagrams
+            .retain(|entry| entry.number != 5 && entry.number != 9);
+        pack.hexagrams[0].all_lines = None;
+        pack.hexagrams[2].lines.pop();
+        pack.hexagrams[3].image = String::new();
+
+        let error = pack.validate().unwrap_err().to_string();
+        assert!(error.contains("5 problem(s)"));
+        assert!(error.contains("hexagram 1: missing statement for all lines changing"));
+        assert!(error.contains("hexagram 3: expected 6 line statements, found 5"));
+        assert!(error.contains("hexagram 4: empty image"));
+        assert!(error.contains("hexagram 5: missing entry"));
+        assert!(error.contains("hexagram 9: missing entry"));
+
+        // Entries are looked up by number, and missing texts are printed as such.
+        assert!(pack.hexagram(5).is_none());
+        assert_eq!(pack.hexagram(6).unwrap().number, 6);
+        assert_eq!(pack.hexagram(64).unwrap().number, 64);
+        let reading = reading_from_throws(&[9, 7, 7, 8, 7, 8], "").unwrap();
+        assert_eq!(reading.present().number, 5);
+        assert_eq!(
+            reading.interpretation(&pack),
+            "Hexagram 5, line 1 (primary): (missing from the text pack)\n"
+        );
+    }
+
+    /// Verifies that malformed files and unknown translations are rejected.
+    #[test]
+    fn test_invalid_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("broken.json"), "{\"name\": \"broken\"")?;
+        fs::write(dir.path().join("pack.txt"), "")?;
+
+        assert!(TextPack::find("broken", Some(dir.path())).is_err());
+        assert!(TextPack::load(&dir.path().join("pack.txt")).is_err());
+        assert!(TextPack::find("missing", Some(dir.path())).is_err());
+        assert!(TextPack::find("missing", None).is_err());
+        assert!(TextPack::find(BUILTIN_TRANSLATION, None).is_ok());
+        Ok(())
+    }
+}
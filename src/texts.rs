@@ -0,0 +1,1863 @@
+//! Module containing the bundled judgment, image, and line texts of the hexagrams.
+//!
+//! The texts are adapted from James Legge's translation of the I Ching (Sacred Books of the East,
+//! vol. XVI, 1882), which is in the public domain. Names have been romanized using pinyin to match
+//! the rest of the crate.
+
+/// The texts associated with a single hexagram.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexagramText {
+    /// The number of the hexagram in the King Wen sequence.
+    pub number: u8,
+
+    /// The judgment of the hexagram.
+    pub judgment: &'static str,
+
+    /// The image of the hexagram.
+    pub image: &'static str,
+
+    /// The statements for each line, from bottom to top.
+    pub lines: [&'static str; 6],
+
+    /// The additional statement used when all six lines are changing. Only hexagrams 1 and 2 have
+    /// one.
+    pub all_lines: Option<&'static str>,
+}
+
+impl HexagramText {
+    /// Returns the statement for the line at the given zero-based index, counting from the bottom.
+    pub fn line(&self, index: usize) -> Option<&'static str> {
+        self.lines.get(index).copied()
+    }
+}
+
+/// Returns the texts of the hexagram with the given number, if it's a valid hexagram number.
+pub fn hexagram_text(number: u8) -> Option<&'static HexagramText> {
+    if !(1..=64).contains(&number) {
+        return None;
+    }
+    HEXAGRAM_TEXTS.get(number as usize - 1)
+}
+
+/// The texts of all the hexagrams, in the order of the King Wen sequence.
+pub static HEXAGRAM_TEXTS: [HexagramText; 64] = [
+    HexagramText {
+        number: 1,
+        judgment: "Qian represents what is great and originating, penetrating, advantageous, \
+            correct and firm.",
+        image: "Heaven, in its motion, gives the idea of strength. The superior man, in \
+            accordance with this, nerves himself to ceaseless activity.",
+        lines: [
+            "In the first line, undivided, we see its subject as the dragon lying hid in the deep. \
+            It is not the time for active doing.",
+            "In the second line, undivided, we see its subject as the dragon appearing in the \
+            field. It will be advantageous to meet with the great man.",
+            "In the third line, undivided, we see its subject as the superior man active and \
+            vigilant all the day, and in the evening still careful and apprehensive. The position \
+            is dangerous, but there will be no mistake.",
+            "In the fourth line, undivided, we see its subject as the dragon looking as if he were \
+            leaping up, but still in the deep. There will be no mistake.",
+            "In the fifth line, undivided, we see its subject as the dragon on the wing in the \
+            sky. It will be advantageous to meet with the great man.",
+            "In the sixth line, undivided, we see its subject as the dragon exceeding the proper \
+            limits. There will be occasion for repentance.",
+        ],
+        all_lines: Some("The lines of this hexagram are all strong and undivided, as appears from \
+            the use of the number nine. If the host of dragons thus appearing were to divest \
+            themselves of their heads, there would be good fortune."),
+    },
+    HexagramText {
+        number: 2,
+        judgment: "Kun represents what is great and originating, penetrating, advantageous, \
+            correct and having the firmness of a mare. When the superior man has to make any \
+            movement, if he take the initiative, he will go astray; if he follow, he will find \
+            his proper lord. The advantageousness will be seen in his getting friends in the \
+            south-west, and losing friends in the north-east. If he rest in correctness and \
+            firmness, there will be good fortune.",
+        image: "The capacity and sustaining power of the earth is what is denoted by Kun. The \
+            superior man, in accordance with this, with his large virtue supports men and things.",
+        lines: [
+            "In the first line, divided, we see its subject treading on hoarfrost. The strong ice \
+            will come by and by.",
+            "The second line, divided, shows the attribute of being straight, square, and great. \
+            Its operation, without repeated efforts, will be in every respect advantageous.",
+            "The third line, divided, shows its subject keeping his excellence under restraint, \
+            but firmly maintaining it. If he should have occasion to engage in the king's \
+            service, though he will not claim the success for himself, he will bring affairs to a \
+            good issue.",
+            "The fourth line, divided, shows the symbol of a sack tied up. There will be no ground \
+            for blame or for praise.",
+            "The fifth line, divided, shows the yellow lower garment. There will be great good \
+            fortune.",
+            "The sixth line, divided, shows dragons fighting in the wild. Their blood is purple \
+            and yellow.",
+        ],
+        all_lines: Some("The lines of this hexagram are all weak and divided, as appears from the \
+            use of the number six. If those who are thus represented be perpetually correct and \
+            firm, advantage will arise."),
+    },
+    HexagramText {
+        number: 3,
+        judgment: "Zhun indicates that in the case which it presupposes there will be great \
+            progress and success, and the advantage will come from being correct and firm. Any \
+            movement in advance should not be lightly undertaken. There will be advantage in \
+            appointing feudal princes.",
+        image: "The trigram representing clouds and that representing thunder form Zhun. The \
+            superior man, in accordance with this, adjusts his measures of government as in \
+            sorting the threads of the warp and woof.",
+        lines: [
+            "The first line, undivided, shows the difficulty its subject has in advancing. It will \
+            be advantageous for him to abide correct and firm; advantageous also to be made a \
+            feudal ruler.",
+            "The second line, divided, shows its subject distressed and obliged to return; even \
+            the horses of her chariot seem to be retreating. But she is not assailed by a robber, \
+            but sought by one who would be her husband. The young lady maintains her firm \
+            correctness, and declines a union. After ten years she will be united, and have \
+            children.",
+            "The third line, divided, shows one following the deer without the guidance of the \
+            forester, and only finding himself in the midst of the forest. The superior man, \
+            acquainted with the secret risks, thinks it better to give up the chase. If he went \
+            forward, he would regret it.",
+            "The fourth line, divided, shows its subject as a lady, the horses of whose chariot \
+            appear in retreat. She seeks, however, the help of him who seeks her to be his wife. \
+            Advance will be fortunate; all will turn out advantageously.",
+            "The fifth line, undivided, shows the difficulties in the way of its subject's \
+            dispensing the rich favours that might be expected from him. With firmness and \
+            correctness there will be good fortune in small things; even with them in great \
+            things there will be evil.",
+            "The topmost line, divided, shows its subject with the horses of his chariot obliged \
+            to retreat, and weeping tears of blood in streams.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 4,
+        judgment: "Meng indicates that in the case which it presupposes there will be progress \
+            and success. I do not go and seek the youthful and inexperienced, but he comes and \
+            seeks me. When he shows the sincerity that marks the first recourse to divination, I \
+            instruct him. If he apply a second and third time, that is troublesome; and I do not \
+            instruct the troublesome. There will be advantage in being firm and correct.",
+        image: "The trigram representing a mountain, and beneath it that for a spring issuing \
+            forth, form Meng. The superior man, in accordance with this, strives to be resolute \
+            in his conduct and nourishes his virtue.",
+        lines: [
+            "The first line, divided, has respect to the dispelling of ignorance. It will be \
+            advantageous to use punishment for that purpose, and to remove the shackles from the \
+            mind. But going on in that way of punishment will give occasion for regret.",
+            "The second line, undivided, shows its subject exercising forbearance with the \
+            ignorant, in which there will be good fortune; and admitting even the goodness of \
+            women, which will also be fortunate. He may be described also as a son able to \
+            sustain the burden of his family.",
+            "The third line, divided, seems to say that one should not marry a woman whose \
+            emotions are moved by a man of wealth, and who will not keep her person from him. In \
+            no way will advantage come from her.",
+            "The fourth line, divided, shows its subject as if bound in chains of ignorance. There \
+            will be occasion for regret.",
+            "The fifth line, divided, shows its subject as a simple lad without experience. There \
+            will be good fortune.",
+            "In the topmost line, undivided, we see one smiting the ignorant youth. But no \
+            advantage will come from doing him an injury. Advantage would come from warding off \
+            injury from him.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 5,
+        judgment: "Xu intimates that, with the sincerity which is declared in it, there will be \
+            brilliant success. With firmness there will be good fortune; and it will be \
+            advantageous to cross the great stream.",
+        image: "The trigram representing clouds ascending over that representing the sky forms \
+            Xu. The superior man, in accordance with this, eats and drinks, feasts and enjoys \
+            himself as if there were nothing else to employ him.",
+        lines: [
+            "The first line, undivided, shows its subject waiting in the distant border. It will \
+            be well for him constantly to maintain the purpose thus shown, in which case there \
+            will be no error.",
+            "The second line, undivided, shows its subject waiting on the sand of the mountain \
+            stream. He will suffer the small injury of being spoken against, but in the end there \
+            will be good fortune.",
+            "The third line, undivided, shows its subject in the mud close by the stream. He \
+            thereby invites the approach of injury.",
+            "The fourth line, divided, shows its subject waiting in the place of blood. But he \
+            will get out of the cavern.",
+            "The fifth line, undivided, shows its subject waiting amidst the appliances of a \
+            feast. Through his firmness and correctness there will be good fortune.",
+            "The topmost line, divided, shows its subject entered into the cavern. But there are \
+            three guests coming, without being urged, to his help. If he receive them \
+            respectfully, there will be good fortune in the end.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 6,
+        judgment: "Song intimates how, though there is sincerity in one's contention, he will yet \
+            meet with opposition and obstruction; but if he cherish an apprehensive caution, \
+            there will be good fortune, while if he must prosecute the contention to the bitter \
+            end, there will be evil. It will be advantageous to see the great man; it will not be \
+            advantageous to cross the great stream.",
+        image: "The trigram representing heaven and that representing water, moving away from \
+            each other, form Song. The superior man, in accordance with this, in the transaction \
+            of affairs takes good counsel about his first steps.",
+        lines: [
+            "The first line, divided, shows its subject not perpetuating the matter about which \
+            the contention is. He will suffer the small injury of being spoken against, but the \
+            end will be fortunate.",
+            "The second line, undivided, shows its subject unequal to the contention. If he retire \
+            and keep concealed where the inhabitants of his city are only three hundred families, \
+            he will fall into no mistake.",
+            "The third line, divided, shows its subject keeping in the old place assigned for his \
+            support, and firmly correct. Perilous as the position is, there will be good fortune \
+            in the end. Should he perchance engage in the king's business, he will not claim the \
+            merit of achievement.",
+            "The fourth line, undivided, shows its subject unequal to the contention. He returns \
+            to the study of Heaven's ordinances, changes his wish to contend, and rests in being \
+            firm and correct. There will be good fortune.",
+            "The fifth line, undivided, shows its subject contending, and with great good fortune.",
+            "The topmost line, undivided, shows how its subject may have the leather belt \
+            conferred on him by the sovereign, and thrice it shall be taken from him in a \
+            morning.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 7,
+        judgment: "Shi indicates how, in the case which it supposes, with firmness and \
+            correctness, and a leader of age and experience, there will be good fortune and no \
+            error.",
+        image: "The trigram representing the earth and in the midst of it that representing water \
+            form Shi. The superior man, in accordance with this, nourishes and educates the \
+            people, and collects from among them the multitudes of the hosts.",
+        lines: [
+            "The first line, divided, shows the host going forth according to the rules for such a \
+            movement. If these be not good, there will be evil.",
+            "The second line, undivided, shows the leader in the midst of the host. There will be \
+            good fortune and no error. The king has thrice conveyed to him his charge.",
+            "The third line, divided, shows how the host may possibly have many inefficient \
+            leaders. There will be evil.",
+            "The fourth line, divided, shows the host in retreat. There is no error.",
+            "The fifth line, divided, shows birds in the fields, which it will be advantageous to \
+            seize and destroy. In that case there will be no error. If the oldest son leads the \
+            host, and younger men idly occupy offices assigned to them, however firm and correct \
+            he may be, there will be evil.",
+            "The topmost line, divided, shows the great ruler delivering his charges, appointing \
+            some to be rulers of states, and others to undertake the headship of clans; but small \
+            men should not be employed in such positions.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 8,
+        judgment: "Bi indicates that under the conditions which it supposes there is good \
+            fortune. But let the principal party intended in it re-examine himself, as if by \
+            divination, whether his virtue be great, unintermitting, and firm. If it be so, there \
+            will be no error. Those who have not rest will then come to him; and with those who \
+            are too late in coming it will be ill.",
+        image: "The trigram representing the earth, and over it that representing water, form Bi. \
+            The ancient kings, in accordance with this, established the various states and \
+            maintained an affectionate relation to their princes.",
+        lines: [
+            "The first line, divided, shows its subject seeking by his sincerity to win the \
+            attachment of his object. There will be no error. Let the breast be full of sincerity \
+            as an earthenware vessel is of its contents, and it will in the end bring other \
+            advantages.",
+            "In the second line, divided, we see the movement towards union and attachment \
+            proceeding from the inward mind. With firm correctness there will be good fortune.",
+            "In the third line, divided, we see its subject seeking for union with such as ought \
+            not to be associated with.",
+            "In the fourth line, divided, we see its subject seeking for union with the one beyond \
+            himself. With firm correctness there will be good fortune.",
+            "The fifth line, undivided, affords the most illustrious instance of seeking union and \
+            attachment. We seem to see in it the king urging his pursuit of the game only in \
+            three directions, and allowing the escape of all the animals before him, while the \
+            people of his towns do not warn one another to prevent it. There will be good \
+            fortune.",
+            "In the topmost line, divided, we see one seeking union and attachment without having \
+            taken the first step to such an end. There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 9,
+        judgment: "Xiao Chu indicates that under its conditions there will be progress and \
+            success. We see dense clouds, but no rain coming from our borders in the west.",
+        image: "The trigram representing the sky, and that representing wind moving above it, \
+            form Xiao Chu. The superior man, in accordance with this, adorns the outward \
+            manifestation of his virtue.",
+        lines: [
+            "The first line, undivided, shows its subject returning and pursuing his own course. \
+            What mistake should he fall into? There will be good fortune.",
+            "The second line, undivided, shows its subject, by the attraction of the former line, \
+            returning to the proper course. There will be good fortune.",
+            "The third line, undivided, suggests the idea of a carriage, the strap beneath which \
+            has been removed, or of a husband and wife looking on each other with averted eyes.",
+            "The fourth line, divided, shows its subject possessed of sincerity. The danger of \
+            bloodshed is thereby averted, and his ground for apprehension dismissed. There will \
+            be no mistake.",
+            "The fifth line, undivided, shows its subject possessed of sincerity, and drawing \
+            others to unite with him. Rich in resources, he employs his neighbours in the same \
+            cause with himself.",
+            "The topmost line, undivided, shows how the rain has fallen, and the onward progress \
+            is stayed; so must we value the full accumulation of virtue. But a wife exercising \
+            restraint, however firm and correct she may be, is in a position of peril, and like \
+            the moon approaching to the full. If the superior man prosecute his measures in such \
+            circumstances, there will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 10,
+        judgment: "Lü suggests the idea of one treading on the tail of a tiger, which does not \
+            bite him. There will be progress and success.",
+        image: "The trigram representing the sky above, and below it that representing the waters \
+            of a marsh, form Lü. The superior man, in accordance with this, discriminates between \
+            high and low, and gives settlement to the aims of the people.",
+        lines: [
+            "The first line, undivided, shows its subject treading his accustomed path. If he go \
+            forward, there will be no error.",
+            "The second line, undivided, shows its subject treading the path that is level and \
+            easy; a quiet and solitary man, to whom, if he be firm and correct, there will be \
+            good fortune.",
+            "The third line, divided, shows a one-eyed man who thinks he can see, a lame man who \
+            thinks he can walk well, one who treads on the tail of a tiger and is bitten. All \
+            this indicates ill fortune. We have a mere bravo acting the part of a great ruler.",
+            "The fourth line, undivided, shows its subject treading on the tail of a tiger. He \
+            becomes full of apprehensive caution, and in the end there will be good fortune.",
+            "The fifth line, undivided, shows the resolute tread of its subject. Though he be firm \
+            and correct, there will be peril.",
+            "The sixth line, undivided, tells us to look at the whole course that is trodden, and \
+            examine the presage which that gives. If it be complete and without failure, there \
+            will be great good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 11,
+        judgment: "In Tai we see the little gone and the great come. It indicates that there will \
+            be good fortune, with progress and success.",
+        image: "The trigrams for heaven and earth in communication together form Tai. The sage \
+            sovereign, in harmony with this, fashions and completes his regulations after the \
+            courses of heaven and earth, and assists the application of the adaptations furnished \
+            by them, in order to benefit the people.",
+        lines: [
+            "The first line, undivided, suggests the idea of grass pulled up, and bringing with it \
+            other stalks with whose roots it is connected. Advance on the part of its subject \
+            will be fortunate.",
+            "The second line, undivided, shows one who can bear with the uncultivated, will cross \
+            the Ho without a boat, does not forget the distant, and has no selfish friendships. \
+            Thus does he prove himself acting in accordance with the course of the due mean.",
+            "The third line, undivided, shows that, while there is no state of peace that is not \
+            liable to be disturbed, and no departure of evil men so that they shall not return, \
+            yet when one is firm and correct, as he realises the distresses that may arise, he \
+            will commit no error. There is no occasion for sadness at the certainty of such \
+            recurrences; and in this mood the happiness of the present may be long enjoyed.",
+            "The fourth line, divided, shows its subject fluttering down, not relying on his own \
+            rich resources, but calling in his neighbours. They all come not as having received \
+            warning, but in the sincerity of their hearts.",
+            "The fifth line, divided, reminds us of king Di Yi's rule about the marriage of his \
+            younger sister. By such a course there is happiness and there will be great good \
+            fortune.",
+            "The sixth line, divided, shows us the city wall returned into the moat. It is not the \
+            time to use the army. The subject of the line may, indeed, announce his orders to the \
+            people of his own city; but however correct and firm he may be, he will have cause \
+            for regret.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 12,
+        judgment: "In Pi there is the want of good understanding between the different classes of \
+            men, and its indication is unfavourable to the firm and correct course of the \
+            superior man. We see in it the great gone and the little come.",
+        image: "The trigrams of heaven and earth, not in intercommunication, form Pi. The \
+            superior man, in accordance with this, restrains the manifestation of his virtue, and \
+            avoids the calamities that threaten him. There is no opportunity of conferring on him \
+            the glory of emolument.",
+        lines: [
+            "The first line, divided, suggests the idea of grass pulled up, and bringing with it \
+            other stalks with whose roots it is connected. With firm correctness on the part of \
+            its subject, there will be good fortune and progress.",
+            "The second line, divided, shows its subject patient and obedient. To the small man \
+            comporting himself so there will be good fortune. If the great man comport himself as \
+            the distress and obstruction require, he will have success.",
+            "The third line, divided, shows its subject ashamed of the purpose folded in his \
+            breast.",
+            "The fourth line, undivided, shows its subject acting in accordance with the \
+            ordination of Heaven, and committing no error. His companions will come and share in \
+            his happiness.",
+            "In the fifth line, undivided, we see him who brings the distress and obstruction to a \
+            close, the great man and fortunate. But let him say, 'We may perish! We may perish!' \
+            So shall the state of things become firm, as if bound to a clump of bushy mulberry \
+            trees.",
+            "The sixth line, undivided, shows the overthrow and removal of the condition of \
+            distress and obstruction. Before this there was that condition. Hereafter there will \
+            be joy.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 13,
+        judgment: "Tong Ren, or union of men, appears here as we find it in the remote districts \
+            of the country, indicating progress and success. It will be advantageous to cross the \
+            great stream. It will be advantageous to maintain the firm correctness of the \
+            superior man.",
+        image: "The trigrams for heaven and fire form Tong Ren. The superior man, in accordance \
+            with this, distinguishes things according to their kinds and classes.",
+        lines: [
+            "The first line, undivided, shows the representative of the union of men just issuing \
+            from his gate. There will be no error.",
+            "The second line, divided, shows the representative of the union of men in relation \
+            with his kindred. There will be occasion for regret.",
+            "The third line, undivided, shows its subject with his arms hidden in the thick grass, \
+            and at the top of a high mound. For three years he makes no demonstration.",
+            "The fourth line, undivided, shows its subject mounted on the city wall; but he does \
+            not proceed to make the attack he contemplates. There will be good fortune.",
+            "In the fifth line, undivided, the representative of the union of men first wails and \
+            cries out, and then laughs. His great host conquers, and he and the subject of the \
+            second line meet together.",
+            "The topmost line, undivided, shows the representative of the union of men in the \
+            suburbs. There will be no occasion for repentance.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 14,
+        judgment: "Da You indicates that, under the circumstances which it implies, there will be \
+            great progress and success.",
+        image: "The trigram for heaven and that of fire above it form Da You. The superior man, \
+            in accordance with this, represses what is evil and gives distinction to what is \
+            good, in sympathy with the excellent Heaven-conferred nature.",
+        lines: [
+            "In the first line, undivided, there is no approach to what is injurious, and there is \
+            no error. Let there be a realisation of the difficulty and danger of the position, \
+            and there will be no error to the end.",
+            "In the second line, undivided, we have a large waggon with its load. In whatever \
+            direction advance is made, there will be no error.",
+            "The third line, undivided, shows us a feudal prince presenting his offerings to the \
+            Son of Heaven. A small man would be unequal to such a duty.",
+            "The fourth line, undivided, shows its subject keeping his great resources under \
+            restraint. There will be no error.",
+            "The fifth line, divided, shows the sincerity of its subject reciprocated by that of \
+            all the others represented in the hexagram. Let him display a proper majesty, and \
+            there will be good fortune.",
+            "The topmost line, undivided, shows its subject with help accorded to him from Heaven. \
+            There will be good fortune, advantage in every respect.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 15,
+        judgment: "Qian indicates progress and success. The superior man, being humble as it \
+            implies, will have a good issue to his undertakings.",
+        image: "The trigram for the earth and that of a mountain in the midst of it form Qian. \
+            The superior man, in accordance with this, diminishes what is excessive in himself, \
+            and increases where there is any defect, bringing about an equality, according to the \
+            nature of the case, in his treatment of himself and others.",
+        lines: [
+            "The first line, divided, shows us the superior man who adds humility to humility. \
+            Even the great stream may be crossed with this, and there will be good fortune.",
+            "The second line, divided, shows us humility that has made itself recognised. With \
+            firm correctness there will be good fortune.",
+            "The third line, undivided, shows the superior man of acknowledged merit. He will \
+            maintain his success to the end, and have good fortune.",
+            "The fourth line, divided, shows one whose action would be in every way advantageous, \
+            stirring up his humility the more.",
+            "The fifth line, divided, shows one who, without being rich, is able to employ his \
+            neighbours. He may advantageously use the force of arms. All his movements will be \
+            advantageous.",
+            "The sixth line, divided, shows us humility that has made itself recognised. The \
+            subject of it will with advantage put his hosts in motion; but he will only punish \
+            his own towns and state.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 16,
+        judgment: "Yu indicates that, in the state which it implies, feudal princes may be set \
+            up, and the hosts put in motion, with advantage.",
+        image: "The trigrams for the earth and thunder issuing from it with its crashing noise \
+            form Yu. The ancient kings, in accordance with this, composed their music and did \
+            honour to virtue, presenting it especially and most grandly to God, when they \
+            associated with Him at the service their highest ancestor and their father.",
+        lines: [
+            "The first line, divided, shows its subject proclaiming his pleasure and satisfaction. \
+            There will be evil.",
+            "The second line, divided, shows one who is firm as a rock. He sees a thing without \
+            waiting till it has come to pass; with his firm correctness there will be good \
+            fortune.",
+            "The third line, divided, shows one looking up for favours, while he indulges the \
+            feeling of pleasure and satisfaction. If he would understand, there will be occasion \
+            for repentance. Even according to the delay of it there will be occasion for \
+            repentance.",
+            "The fourth line, undivided, shows him from whom the harmony and satisfaction come. \
+            Great is the success which he obtains. Let him not allow suspicions to enter his \
+            mind, and thus friends will gather around him, as the hair is collected by the clasp.",
+            "The fifth line, divided, shows one with a chronic complaint, but who lives on without \
+            dying.",
+            "The topmost line, divided, shows its subject with darkened mind devoted to the \
+            pleasure and satisfaction of the time; but if he change his course even when it may \
+            be considered as completed, there will be no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 17,
+        judgment: "Sui indicates that under its conditions there will be great progress and \
+            success. But it will be advantageous to be firm and correct. There will then be no \
+            error.",
+        image: "The trigram for the waters of a marsh and that for thunder hidden in the midst of \
+            it form Sui. The superior man, in accordance with this, when it is getting towards \
+            dark, enters his house and rests.",
+        lines: [
+            "The first line, undivided, shows us one changing the object of his pursuit; but if he \
+            be firm and correct, there will be good fortune. Going beyond his own gate to find \
+            associates, he will achieve merit.",
+            "The second line, divided, shows us one who cleaves to the little boy, and lets go the \
+            man of age and experience.",
+            "The third line, divided, shows us one who cleaves to the man of age and experience, \
+            and lets go the little boy. Such following will get what it seeks; but it will be \
+            advantageous to adhere to what is firm and correct.",
+            "The fourth line, undivided, shows us one followed and obtaining adherents. Though he \
+            be firm and correct, there will be evil. If he be sincere however in his course, and \
+            make that evident, into what error can he fall?",
+            "The fifth line, undivided, shows us the ruler sincere in fostering all that is \
+            excellent. There will be good fortune.",
+            "The topmost line, divided, shows us that sincerity firmly held and clung to, yea, and \
+            bound fast. We see the king with it presenting his offerings on the western mountain.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 18,
+        judgment: "Gu indicates great progress and success to him who deals properly with the \
+            condition represented by it. There will be advantage in efforts like that of crossing \
+            the great stream. He should weigh well, however, the events of three days before the \
+            turning point, and those to be done three days after it.",
+        image: "The trigram for a mountain, and below it that for wind, form Gu. The superior \
+            man, in accordance with this, addresses himself to help the people and nourish his \
+            own virtue.",
+        lines: [
+            "The first line, divided, shows a son dealing with the troubles caused by his father. \
+            If he be an able son, the father will escape the blame of having erred. The position \
+            is perilous, but there will be good fortune in the end.",
+            "The second line, undivided, shows a son dealing with the troubles caused by his \
+            mother. He should not carry his firm correctness to the utmost.",
+            "The third line, undivided, shows a son dealing with the troubles caused by his \
+            father. There may be some small occasion for repentance, but there will not be any \
+            great error.",
+            "The fourth line, divided, shows a son viewing indulgently the troubles caused by his \
+            father. If he go forward, he will find cause to regret it.",
+            "The fifth line, divided, shows a son dealing with the troubles caused by his father. \
+            He obtains the praise of using the fit instrument for his work.",
+            "The sixth line, undivided, shows us one who does not serve either king or feudal \
+            lord, but in a lofty spirit prefers to attend to his own affairs.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 19,
+        judgment: "Lin indicates that under the conditions supposed in it there will be great \
+            progress and success, while it will be advantageous to be firmly correct. In the \
+            eighth month there will be evil.",
+        image: "The trigram for the waters of a marsh and that for the earth above it form Lin. \
+            The superior man, in accordance with this, has his purposes of instruction that are \
+            inexhaustible, and nourishes and supports the people without limit.",
+        lines: [
+            "The first line, undivided, shows its subject advancing in company with the subject of \
+            the second line. Through his firm correctness there will be good fortune.",
+            "The second line, undivided, shows its subject advancing in company with the subject \
+            of the first line. There will be good fortune; advancing will be in every way \
+            advantageous.",
+            "The third line, divided, shows one well pleased to advance, but whose action will be \
+            in no way advantageous. If he become anxious about it however, there will be no \
+            error.",
+            "The fourth line, divided, shows one advancing in the highest mode. There will be no \
+            error.",
+            "The fifth line, divided, shows the advance of wisdom, such as befits the great ruler. \
+            There will be good fortune.",
+            "The sixth line, divided, shows the advance of honesty and generosity. There will be \
+            good fortune, and no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 20,
+        judgment: "Guan shows how the worshipper, who has washed his hands, but not yet presented \
+            his offerings, should be full of sincerity and with an appearance of dignity, so that \
+            those who look on are transformed.",
+        image: "The trigram representing the earth, and that for wind moving above it, form Guan. \
+            The ancient kings, in accordance with this, examined the different regions of the \
+            kingdom, to see the ways of the people, and set forth their instructions.",
+        lines: [
+            "The first line, divided, shows the looking of a lad; not blamable in men of inferior \
+            rank, but matter for regret in superior men.",
+            "The second line, divided, shows one peeping out from a door. It would be advantageous \
+            if it were merely the firm correctness of a female.",
+            "The third line, divided, shows one looking at the course of his own life, to advance \
+            or recede accordingly.",
+            "The fourth line, divided, shows one contemplating the glory of the kingdom. It will \
+            be advantageous for him, being such as he is, to seek to be a guest of the king.",
+            "The fifth line, undivided, shows its subject contemplating his own life. A superior \
+            man, he will thus fall into no error.",
+            "The sixth line, undivided, shows its subject contemplating his character to see if it \
+            be indeed that of a superior man. He will not fall into error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 21,
+        judgment: "Shi He indicates successful progress in the condition of things which it \
+            supposes. It will be advantageous to use legal constraints.",
+        image: "The trigrams representing thunder and lightning form Shi He. The ancient kings, \
+            in accordance with this, framed their penalties with intelligence, and promulgated \
+            their laws.",
+        lines: [
+            "In the first line, undivided, we see one with his feet in the stocks and deprived of \
+            his toes. There will be no error.",
+            "In the second line, divided, we see one biting through the soft flesh, and going on \
+            to bite off the nose. There will be no error.",
+            "In the third line, divided, we see one gnawing dried flesh, and meeting with what is \
+            disagreeable. There will be occasion for some small regret, but no great error.",
+            "The fourth line, undivided, shows one gnawing the flesh dried on the bone, and \
+            getting the pledges of money and arrows. It will be advantageous to him to realise \
+            the difficulty of his task and be firm, in which case there will be good fortune.",
+            "The fifth line, divided, shows one gnawing at dried flesh, and finding the yellow \
+            gold. Let him be firm and correct, realising the peril of his position. There will be \
+            no error.",
+            "The sixth line, undivided, shows one wearing the cangue, and deprived of his ears. \
+            There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 22,
+        judgment: "Bi indicates that there should be free course in what it denotes. There will \
+            be little advantage however if it be allowed to advance and take the lead.",
+        image: "The trigram representing a mountain and that for fire under it form Bi. The \
+            superior man, in accordance with this, throws a brilliancy around his various \
+            processes of government, but does not dare in a similar way to decide cases of \
+            criminal litigation.",
+        lines: [
+            "The first line, undivided, shows one adorning the way of his feet. He can discard a \
+            carriage and walk on foot.",
+            "The second line, divided, shows one adorning his beard.",
+            "The third line, undivided, shows its subject with the appearance of being adorned and \
+            bedewed with rich favours. But let him ever maintain his firm correctness, and there \
+            will be good fortune.",
+            "The fourth line, divided, shows one looking as if adorned, but only in white. As if \
+            mounted on a white horse, and furnished with wings, he seeks union with the subject \
+            of the first line, while the intervening third pursues, not as a robber, but intent \
+            on a matrimonial alliance.",
+            "The fifth line, divided, shows its subject adorned by the occupants of the heights \
+            and gardens. He bears his roll of silk, small and slight. He may appear stingy; but \
+            there will be good fortune in the end.",
+            "The sixth line, undivided, shows one with white as his only ornament. There will be \
+            no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 23,
+        judgment: "Bo indicates that in the state which it symbolises it will not be advantageous \
+            to make a movement in any direction whatever.",
+        image: "The trigrams representing the earth, and above it that for a mountain, which \
+            adheres to the earth, form Bo. Superiors, in accordance with this, seek to strengthen \
+            those below them, to secure the peace and stability of their own position.",
+        lines: [
+            "The first line, divided, shows one overturning the couch by injuring its legs. The \
+            injury will go on to the destruction of all firm correctness, and there will be evil.",
+            "The second line, divided, shows one overthrowing the couch by injuring its frame. The \
+            injury will go on to the destruction of all firm correctness, and there will be evil.",
+            "The third line, divided, shows its subject among the overthrowers; but there will be \
+            no error.",
+            "The fourth line, divided, shows its subject having overthrown the couch, and going to \
+            injure the skin of him who lies on it. There will be evil.",
+            "The fifth line, divided, shows its subject leading on the others like a string of \
+            fishes, and obtaining for them the favour that lights on the inmates of the palace. \
+            There will be advantage in every way.",
+            "The sixth line, undivided, shows its subject as a great fruit which has not been \
+            eaten. The superior man finds the people as a chariot carrying him. The small men by \
+            their course overthrow their own dwellings.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 24,
+        judgment: "Fu indicates that there will be free course and progress in what it denotes. \
+            The subject of it finds no one to distress him in his exits and entrances; friends \
+            come to him, and no error is committed. He will return and repeat his proper course. \
+            In seven days comes his return. There will be advantage in whatever direction \
+            movement is made.",
+        image: "The trigram representing the earth and that for thunder in the midst of it form \
+            Fu. The ancient kings, in accordance with this, on the day of the winter solstice, \
+            shut the gates of the passes from one state to another, so that the travelling \
+            merchants could not pass through them, nor the princes go on with the inspection of \
+            their states.",
+        lines: [
+            "The first line, undivided, shows its subject returning from an error of no great \
+            extent, which would not proceed to anything requiring repentance. There will be great \
+            good fortune.",
+            "The second line, divided, shows the admirable return of its subject. There will be \
+            good fortune.",
+            "The third line, divided, shows one who has made repeated returns. The position is \
+            perilous, but there will be no error.",
+            "The fourth line, divided, shows its subject moving right in the centre among those \
+            represented by the other divided lines, and yet returning alone to his proper path.",
+            "The fifth line, divided, shows the noble return of its subject. There will be no \
+            ground for repentance.",
+            "The sixth line, divided, shows its subject all astray on the subject of returning. \
+            There will be evil. There will be calamities and errors. If with his views he put the \
+            hosts in motion, the end will be a great defeat, whose issues will extend to the \
+            ruler of the state. Even in ten years he will not be able to repair the disaster.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 25,
+        judgment: "Wu Wang indicates great progress and success, while there will be advantage in \
+            being firm and correct. If its subject and his action be not correct, he will fall \
+            into errors, and it will not be advantageous for him to move in any direction.",
+        image: "The thunder rolls all under the sky, and to every thing there is given its \
+            nature, free from all insincerity. The ancient kings, in accordance with this, made \
+            their regulations in complete accordance with the seasons, thereby nourishing all \
+            things.",
+        lines: [
+            "The first line, undivided, shows its subject free from all insincerity. His advance \
+            will be accompanied with good fortune.",
+            "The second line, divided, shows one who reaps without having ploughed, and gathers \
+            the produce of his third year's fields without having cultivated them the first year \
+            for that end. To such a one there will be advantage in whatever direction he may \
+            move.",
+            "The third line, divided, shows calamity happening to one who is free from \
+            insincerity; as in the case of an ox that has been tied up. A passer-by finds it and \
+            carries it off, while the people in the neighbourhood have the calamity of being \
+            accused and apprehended.",
+            "The fourth line, undivided, shows a case in which, if its subject can remain firm and \
+            correct, there will be no error.",
+            "The fifth line, undivided, shows one who is free from insincerity, and yet has fallen \
+            ill. Let him not use medicine, and he will have occasion for joy in his recovery.",
+            "The topmost line, undivided, shows its subject free from insincerity, yet sure to \
+            fall into error, if he take action. His action will not be advantageous in any way.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 26,
+        judgment: "Under the conditions of Da Chu it will be advantageous to be firm and correct. \
+            If its subject do not seek to enjoy his revenues in his own family, there will be \
+            good fortune. It will be advantageous for him to cross the great stream.",
+        image: "The trigram representing a mountain, and in the midst of it that representing \
+            heaven, form Da Chu. The superior man, in accordance with this, stores largely in his \
+            memory the words and deeds of former men, to subserve the accumulation of his virtue.",
+        lines: [
+            "The first line, undivided, shows its subject in a position of peril. It will be \
+            advantageous for him to stop his advance.",
+            "The second line, undivided, shows a carriage with the strap under it removed.",
+            "The third line, undivided, shows its subject urging his way with good horses. It will \
+            be advantageous for him to realise the difficulty of his course, and to be firm and \
+            correct, exercising himself daily in his charioteering and methods of defence; then \
+            there will be advantage in whatever direction he may advance.",
+            "The fourth line, divided, shows the young bull, and the piece of wood over his horns. \
+            There will be great good fortune.",
+            "The fifth line, divided, shows the teeth of a castrated hog. There will be good \
+            fortune.",
+            "The sixth line, undivided, shows its subject in command of the firmament of heaven. \
+            There will be progress.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 27,
+        judgment: "Yi indicates that with firm correctness there will be good fortune in what is \
+            denoted by it. We must look at what we are seeking to nourish, and by the exercise of \
+            our thoughts seek for the proper aliment.",
+        image: "The trigram representing a mountain and under it that for thunder form Yi. The \
+            superior man, in accordance with this, enjoins watchfulness over our words, and the \
+            temperate regulation of our eating and drinking.",
+        lines: [
+            "The first line, undivided, seems to be addressed thus: 'You leave your efficacious \
+            tortoise, and look at me till your lower jaw hangs down.' There will be evil.",
+            "The second line, divided, shows one looking downwards for nourishment, which is \
+            contrary to what is proper; or seeking it from the height above, advance towards \
+            which will lead to evil.",
+            "The third line, divided, shows one acting contrary to the method of nourishing. \
+            However firm he may be, there will be evil. For ten years let him not take any \
+            action, for it will not be in any way advantageous.",
+            "The fourth line, divided, shows one looking downwards for the power to nourish. There \
+            will be good fortune. Looking with a tiger's downward unwavering glare, and with his \
+            desire that impels him to spring after spring, he will fall into no error.",
+            "The fifth line, divided, shows one acting contrary to what is regular and proper; but \
+            if he abide in firmness, there will be good fortune. He should not, however, try to \
+            cross the great stream.",
+            "The sixth line, undivided, shows him from whom comes the nourishing. His position is \
+            perilous, but there will be good fortune. It will be advantageous to cross the great \
+            stream.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 28,
+        judgment: "Da Guo suggests to us a beam that is weak. There will be advantage in moving \
+            under its conditions in any direction whatever; there will be success.",
+        image: "The trigram representing trees hidden beneath that for the waters of a marsh \
+            forms Da Guo. The superior man, in accordance with this, stands up alone and has no \
+            fear, and keeps retired from the world without regret.",
+        lines: [
+            "The first line, divided, shows one placing mats of the white mao grass under things \
+            set on the ground. There will be no error.",
+            "The second line, undivided, shows a decayed willow producing shoots, or an old \
+            husband in possession of his young wife. There will be advantage in every way.",
+            "The third line, undivided, shows a beam that is weak. There will be evil.",
+            "The fourth line, undivided, shows a beam curving upwards. There will be good fortune. \
+            If the subject of it look for other help, there will be cause for regret.",
+            "The fifth line, undivided, shows a decayed willow producing flowers, or an old wife \
+            in possession of her young husband. There will be occasion neither for blame nor for \
+            praise.",
+            "The sixth line, divided, shows its subject with extraordinary boldness wading through \
+            a stream, till the water hides the crown of his head. There will be evil, but no \
+            ground for blame.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 29,
+        judgment: "Kan, here repeated, shows the possession of sincerity, through which the mind \
+            is penetrating. Action in accordance with this will be of high value.",
+        image: "The representation of water flowing on continuously forms the repeated Kan. The \
+            superior man, in accordance with this, maintains constantly the virtue of his heart \
+            and the integrity of his conduct, and practises the business of instruction.",
+        lines: [
+            "The first line, divided, shows its subject in the double defile, and yet entering a \
+            cavern within it. There will be evil.",
+            "The second line, undivided, shows its subject in all the peril of the defile. He \
+            will, however, get a little of the deliverance that he seeks.",
+            "The third line, divided, shows its subject, whether he comes or goes, confronted by a \
+            defile. All is peril to him and unrest. His endeavours will lead him into the cavern \
+            of the pit. There should be no action in such a case.",
+            "The fourth line, divided, shows its subject at a feast, with simply a bottle of \
+            spirits, and a subsidiary basket of rice, while the cups and bowls are only of \
+            earthenware. He introduces his important lessons as his ruler's intelligence admits. \
+            There will in the end be no error.",
+            "The fifth line, undivided, shows the water of the defile not yet full, so that it \
+            might flow away; but order will soon be brought about. There will be no error.",
+            "The topmost line, divided, shows its subject bound with cords of three strands or two \
+            strands, and placed in the thicket of thorns. But in three years he does not learn \
+            the course to pursue. There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 30,
+        judgment: "Li indicates that, in regard to what it denotes, it will be advantageous to be \
+            firm and correct, and that thus there will be free course and success. Let its \
+            subject also nourish a docility like that of the cow, and there will be good fortune.",
+        image: "The trigram for brightness, repeated, forms Li. The great man, in accordance with \
+            this, cultivates more and more his brilliant virtue, and diffuses its brightness over \
+            the four quarters of the land.",
+        lines: [
+            "The first line, undivided, shows one ready to move with confused steps. But he treads \
+            at the same time reverently, and there will be no mistake.",
+            "The second line, divided, shows its subject in his place in yellow. There will be \
+            great good fortune.",
+            "The third line, undivided, shows its subject in a position like that of the declining \
+            sun. Instead of playing on his instrument of earthenware, and singing to it, he \
+            utters the groans of an old man of eighty. There will be evil.",
+            "The fourth line, undivided, shows the manner of its subject's coming. How abrupt it \
+            is, as with fire, with death, to be rejected by all!",
+            "The fifth line, divided, shows its subject as one with tears flowing in torrents, and \
+            groaning in sorrow. There will be good fortune.",
+            "The topmost line, undivided, shows the king employing its subject in his punitive \
+            expeditions. Achieving admirable merit, he breaks only the chiefs, and does not \
+            punish their followers. There will be no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 31,
+        judgment: "Xian indicates that, on the fulfilment of the conditions implied in it, there \
+            will be free course and success. Its advantageousness will depend on the being firm \
+            and correct, as in marrying a young lady. There will be good fortune.",
+        image: "The trigram representing a mountain and above it that for the waters of a marsh \
+            form Xian. The superior man, in accordance with this, keeps his mind free from \
+            preoccupation, and open to receive the influences of others.",
+        lines: [
+            "The first line, divided, shows one moving his great toes.",
+            "The second line, divided, shows one moving the calves of his leg. There will be evil. \
+            If he abide quiet in his place, there will be good fortune.",
+            "The third line, undivided, shows one moving his thighs, and keeping close hold of \
+            those whom he follows. Going forward in this way will cause regret.",
+            "The fourth line, undivided, shows that firm correctness which will lead to good \
+            fortune, and prevent all occasion for repentance. If its subject be unsettled in his \
+            movements, only his friends will follow his purpose.",
+            "The fifth line, undivided, shows one moving the flesh along the spine above the \
+            heart. There will be no occasion for repentance.",
+            "The sixth line, divided, shows one moving his jaws and tongue.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 32,
+        judgment: "Heng indicates successful progress and no error in what it denotes. But the \
+            advantage will come from being firm and correct; and movement in any direction \
+            whatever will be advantageous.",
+        image: "The trigram representing thunder and that for wind form Heng. The superior man, \
+            in accordance with this, stands firm, and does not change his method of operation.",
+        lines: [
+            "The first line, divided, shows its subject deeply desirous of long continuance. Even \
+            with firm correctness there will be evil; there will be no advantage in any way.",
+            "The second line, undivided, shows all occasion for repentance disappearing.",
+            "The third line, undivided, shows one who does not continuously maintain his virtue. \
+            There are those who will impute this to him as a disgrace. However firm he may be, \
+            there will be ground for regret.",
+            "The fourth line, undivided, shows a field where there is no game.",
+            "The fifth line, divided, shows its subject continuously maintaining the virtue \
+            indicated by it. In a wife this will be fortunate; in a husband, evil.",
+            "The sixth line, divided, shows its subject exciting himself to long continuance. \
+            There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 33,
+        judgment: "Dun indicates successful progress in its circumstances. To a small extent it \
+            will still be advantageous to be firm and correct.",
+        image: "The trigram representing the sky and below it that for a mountain form Dun. The \
+            superior man, in accordance with this, keeps small men at a distance, not by showing \
+            that he hates them, but by his own dignified gravity.",
+        lines: [
+            "The first line, divided, shows a retiring tail. The position is perilous. No movement \
+            in any direction should be made.",
+            "The second line, divided, shows its subject holding his purpose fast as if by a thong \
+            made from the hide of a yellow ox, which cannot be broken.",
+            "The third line, undivided, shows one retiring but bound, to his distress and peril. \
+            If he were to deal with his binders as in nourishing a servant or concubine, it would \
+            be fortunate for him.",
+            "The fourth line, undivided, shows its subject retiring notwithstanding his likings. \
+            In a superior man this will lead to good fortune; a small man cannot attain to this.",
+            "The fifth line, undivided, shows its subject retiring in an admirable way. With firm \
+            correctness there will be good fortune.",
+            "The sixth line, undivided, shows its subject retiring in a noble way. It will be \
+            advantageous in every respect.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 34,
+        judgment: "Da Zhuang indicates that under the conditions which it symbolises it will be \
+            advantageous to be firm and correct.",
+        image: "The trigram representing heaven and above it that for thunder form Da Zhuang. The \
+            superior man, in accordance with this, does not take a step which is not according to \
+            propriety.",
+        lines: [
+            "The first line, undivided, shows its subject manifesting his strength in his toes. \
+            But advance will lead to evil, most certainly.",
+            "The second line, undivided, shows that with firm correctness there will be good \
+            fortune.",
+            "The third line, undivided, shows, in the case of a small man, one using all his \
+            strength; and in the case of a superior man, one whose rule is not to do so. Even \
+            with firm correctness the position would be perilous. The exercise of strength in it \
+            might be compared to the case of a ram butting against a fence, and getting his horns \
+            entangled.",
+            "The fourth line, undivided, shows a case in which firm correctness leads to good \
+            fortune, and occasion for repentance disappears. We see the fence opened without the \
+            horns being entangled. The strength is like that in the wheel-spokes of a large \
+            waggon.",
+            "The fifth line, divided, shows one who loses his ram-like strength in the ease of his \
+            position. But there will be no occasion for repentance.",
+            "The sixth line, divided, shows one who may be compared to the ram butting against the \
+            fence, and unable either to retreat or to advance as he would fain do. There will not \
+            be advantage in any respect; but if he realise the difficulty of his position, there \
+            will be good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 35,
+        judgment: "In Jin we see a prince who secures the tranquillity of the people presented on \
+            that account with numerous horses by the king, and three times in a day received at \
+            interviews.",
+        image: "The trigram representing the earth and that for the bright sun coming forth above \
+            it form Jin. The superior man, according to this, gives himself to make more \
+            brilliant his bright virtue.",
+        lines: [
+            "The first line, divided, shows one wishing to advance, and at the same time kept \
+            back. Let him be firm and correct, and there will be good fortune. If trust be not \
+            reposed in him, let him maintain a large and generous mind, and there will be no \
+            error.",
+            "The second line, divided, shows its subject with the appearance of advancing, and yet \
+            of being sorrowful. If he be firm and correct, there will be good fortune. He will \
+            receive this great blessing from his grandmother.",
+            "The third line, divided, shows its subject trusted by all around him. All occasion \
+            for repentance will disappear.",
+            "The fourth line, undivided, shows its subject with the appearance of advancing, but \
+            like a marmot. However firm and correct he may be, the position is one of peril.",
+            "The fifth line, divided, shows how all occasion for repentance disappears from its \
+            subject. But let him not concern himself about whether he shall fail or succeed. To \
+            advance will be fortunate, and in every way advantageous.",
+            "The topmost line, undivided, shows one advancing his horns. But he only uses them to \
+            punish the rebellious people of his own city. The position is perilous, but there \
+            will be good fortune. Yet however firm and correct he may be, there will be occasion \
+            for regret.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 36,
+        judgment: "Ming Yi indicates that in the circumstances which it denotes it will be \
+            advantageous to realise the difficulty of the position, and maintain firm \
+            correctness.",
+        image: "The trigram representing the earth and that for the bright sun entering within it \
+            form Ming Yi. The superior man, in accordance with this, conducts his management of \
+            men; he shows his intelligence by keeping it obscured.",
+        lines: [
+            "The first line, undivided, shows its subject, in the condition indicated by Ming Yi, \
+            flying, but with drooping wings. When the superior man is revolving his going away, \
+            he may be for three days without eating. Wherever he goes, the people there may speak \
+            derisively of him.",
+            "The second line, divided, shows its subject, in the condition indicated by Ming Yi, \
+            wounded in the left thigh. He saves himself by the strength of a swift horse; and is \
+            fortunate.",
+            "The third line, undivided, shows its subject, in the condition indicated by Ming Yi, \
+            hunting in the south and taking the great chief of the darkness. He should not be \
+            eager to make all correct at once.",
+            "The fourth line, divided, shows its subject just entered into the left side of the \
+            belly of the darkness. He finds the mind of the lord of the darkness, and goes forth \
+            and leaves the gate and courtyard.",
+            "The fifth line, divided, shows how the count of Ji fulfilled the condition indicated \
+            by Ming Yi. It will be advantageous to be firm and correct.",
+            "The sixth line, divided, shows the case where there is no light, but only obscurity. \
+            Its subject had at first ascended to the top of the sky; his future shall be to go \
+            into the earth.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 37,
+        judgment: "For the realisation of what is taught in Jia Ren, it is advantageous that the \
+            wife be firm and correct.",
+        image: "The trigram representing fire, and that for wind coming forth from it, form Jia \
+            Ren. The superior man, in accordance with this, orders his words according to the \
+            truth of things, and his conduct so that it is uniformly consistent.",
+        lines: [
+            "The first line, undivided, shows its subject establishing restrictive regulations in \
+            his household. Occasion for repentance will disappear.",
+            "The second line, divided, shows its subject taking nothing on herself, but in the \
+            centre attending to the preparation of the food. Through her firm correctness there \
+            will be good fortune.",
+            "The third line, undivided, shows its subject treating the members of the household \
+            with stern severity. There will be occasion for repentance, there will be peril, but \
+            there will be good fortune. If the wife and children were to be smirking and \
+            chattering, in the end there would be occasion for regret.",
+            "The fourth line, divided, shows its subject enriching the family. There will be great \
+            good fortune.",
+            "The fifth line, undivided, shows the influence of the king extending to his family. \
+            There need be no anxiety; there will be good fortune.",
+            "The sixth line, undivided, shows its subject possessed of sincerity and arrayed in \
+            majesty. In the end there will be good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 38,
+        judgment: "Kui indicates that, notwithstanding the condition of things which it denotes, \
+            in small matters there will still be good success.",
+        image: "The trigram representing fire above, and that for the waters of a marsh below, \
+            form Kui. The superior man, in accordance with this, where there is a general \
+            agreement, yet admits diversity.",
+        lines: [
+            "In the first line, undivided, we see that to its subject occasion for repentance will \
+            disappear. He has lost his horses, but let him not seek for them; they will return of \
+            themselves. Should he meet with bad men, he will not err in communicating with them.",
+            "The second line, undivided, shows its subject happening to meet with his lord in a \
+            bye-passage. There will be no error.",
+            "In the third line, divided, we see one whose carriage is dragged back, while the oxen \
+            in it are pushed back, and he is himself subjected to the shaving of his head and the \
+            cutting off of his nose. There is no good beginning, but there will be a good end.",
+            "The fourth line, undivided, shows its subject solitary amidst the prevailing \
+            disunion. But he meets with the good man represented by the first line, and they \
+            blend their sincere desires together. The position is one of peril, but there will be \
+            no mistake.",
+            "The fifth line, divided, shows that to its subject occasion for repentance will \
+            disappear. With his relative and minister he unites closely and readily as if he were \
+            biting through a piece of skin. When he goes forward with this help, what error can \
+            there be?",
+            "The sixth line, undivided, shows its subject solitary amidst the prevailing disunion. \
+            He seems to see a pig bearing on its back a load of mud, or a carriage full of \
+            ghosts. He first bends his bow against him, and afterwards unbends it, for he sees \
+            that he is not an assailant to injure, but a near relative. Going forward, he shall \
+            meet with genial rain, and there will be good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 39,
+        judgment: "In the state indicated by Jian advantage will be found in the south-west, and \
+            the contrary in the north-east. It will be advantageous also to meet with the great \
+            man. In these circumstances, with firmness and correctness, there will be good \
+            fortune.",
+        image: "The trigram representing a mountain, and above it that for water, form Jian. The \
+            superior man, in accordance with this, turns round and examines himself, and \
+            cultivates his virtue.",
+        lines: [
+            "From the first line, divided, we learn that advance on the part of its subject will \
+            lead to greater difficulties, while remaining stationary will afford ground for \
+            praise.",
+            "The second line, divided, shows the minister of the king struggling with difficulty \
+            on difficulty, and not with a view to his own advantage.",
+            "The third line, undivided, shows its subject advancing, but only to greater \
+            difficulty. He remains stationary, and returns to his former associates.",
+            "The fourth line, divided, shows its subject advancing, but only to greater \
+            difficulties. He remains stationary, and unites with the subject of the line above.",
+            "The fifth line, undivided, shows its subject struggling with the greatest \
+            difficulties, while friends are coming to help him.",
+            "The topmost line, divided, shows its subject advancing only to increase the \
+            difficulties, while his remaining stationary will be productive of great merit. There \
+            will be good fortune, and it will be advantageous to meet with the great man.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 40,
+        judgment: "In the state indicated by Xie advantage will be found in the south-west. If no \
+            further operations be called for, there will be good fortune in coming back to the \
+            old conditions. If some operations be called for, there will be good fortune in the \
+            early conducting of them.",
+        image: "The trigrams representing thunder and rain, with these phenomena in a state of \
+            manifestation, form Xie. The superior man, in accordance with this, forgives errors, \
+            and deals gently with crimes.",
+        lines: [
+            "The first line, divided, tells us that its subject will commit no error.",
+            "The second line, undivided, shows its subject catching, in the hunting field, three \
+            foxes, and obtaining the yellow arrows. With firm correctness there will be good \
+            fortune.",
+            "The third line, divided, shows a porter with his burden, yet riding in a carriage. He \
+            will only tempt robbers to attack him. However firm and correct he may try to be, \
+            there will be cause for regret.",
+            "To the subject of the fourth line, undivided, it is said: 'Remove your toes. Friends \
+            will then come, between you and whom there will be mutual confidence.'",
+            "The fifth line, divided, shows how its subject, being a superior man, executes his \
+            function of removing whatever is injurious to the idea of the hexagram, in which case \
+            there will be good fortune, and confidence in him will be shown even by the small \
+            men.",
+            "In the sixth line, divided, we see a feudal prince with his bow shooting at a falcon \
+            on the top of a high wall, and hitting it. The effect of his action will be in every \
+            way advantageous.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 41,
+        judgment: "In what is denoted by Sun, if there be sincerity in him who employs it, there \
+            will be great good fortune: freedom from error; firmness and correctness that can be \
+            maintained; and advantage in every movement that shall be made. In what shall this \
+            sincerity in the exercise of Sun be employed? Even in sacrifice two baskets of grain, \
+            though there be nothing else, may be presented.",
+        image: "The trigram representing a mountain and beneath it that for the waters of a marsh \
+            form Sun. The superior man, in accordance with this, restrains his wrath and \
+            represses his desires.",
+        lines: [
+            "The first line, undivided, shows its subject suspending his own affairs, and hurrying \
+            away to help the subject of the fourth line. He will commit no error, but let him \
+            consider how far he should contribute of what is his to the other.",
+            "The second line, undivided, shows that it will be advantageous for its subject to \
+            maintain a firm correctness, and that action on his part will be evil. Without \
+            diminishing his own, he can give increase to his correlate.",
+            "The third line, divided, shows how of three men walking together, the number is \
+            diminished by one; and how one, walking, finds his friend.",
+            "The fourth line, divided, shows its subject diminishing the ailment under which he \
+            labours by making the subject of the first line hasten to his help, and make him \
+            glad. There will be no error.",
+            "The fifth line, divided, shows parties adding to the stores of its subject ten pairs \
+            of tortoise shells, and accepting no refusal. There will be great good fortune.",
+            "The sixth line, undivided, shows its subject giving increase to others without taking \
+            from his own. There will be no error. With firm correctness there will be good \
+            fortune. There will be advantage in every movement that shall be made. He will find \
+            ministers more than can be counted by their clans.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 42,
+        judgment: "Yi indicates that in the state which it denotes there will be advantage in \
+            every movement which shall be undertaken, that it will be advantageous even to cross \
+            the great stream.",
+        image: "The trigram representing wind and that for thunder form Yi. The superior man, in \
+            accordance with this, when he sees what is good, moves towards it; and when he sees \
+            his errors, he turns from them.",
+        lines: [
+            "The first line, undivided, shows that it will be advantageous for its subject in his \
+            position to make a great movement. If it be greatly fortunate, no blame will be \
+            imputed to him.",
+            "The second line, divided, shows parties adding to the stores of its subject ten pairs \
+            of tortoise shells whose oracles cannot be opposed. Let him persevere in being firm \
+            and correct, and there will be good fortune. Let the king, having the virtues thus \
+            distinguished, employ them in presenting his offerings to God, and there will be good \
+            fortune.",
+            "The third line, divided, shows increase given to its subject by means of what is \
+            evil, so that he shall be led to good, and be without blame. Let him be sincere and \
+            pursue the path of the mean, and he will secure the recognition of the ruler, like an \
+            officer who announces himself to his prince by the symbol of his rank.",
+            "The fourth line, divided, shows its subject pursuing the due course. His advice to \
+            his prince is followed. He can with advantage be relied on in such a movement as that \
+            of removing the capital.",
+            "The fifth line, undivided, shows its subject with sincere heart seeking to benefit \
+            all below. There need be no question about it; the result will be great good fortune. \
+            All below will with sincere heart acknowledge his goodness.",
+            "The sixth line, undivided, shows its subject to whose increase none will contribute, \
+            while many will seek to assail him. He observes no regular rule in the ordering of \
+            his heart. There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 43,
+        judgment: "Guai requires that the culprit's guilt be exhibited in the royal court, and \
+            that a sincere and earnest appeal be made for sympathy and support, with a \
+            consciousness of the peril involved. It requires also that announcement be made in \
+            his own city, and that it will not be well to have recourse at once to arms. In this \
+            way there will be advantage in whatever may be gone forward to.",
+        image: "The trigram for heaven and that of the waters of a marsh mounting above it form \
+            Guai. The superior man, in accordance with this, bestows emolument on those below \
+            him, and dislikes allowing his gifts to accumulate undispensed.",
+        lines: [
+            "The first line, undivided, shows its subject in the pride of strength advancing with \
+            his toes. He goes forward, but will not succeed. There will be ground for blame.",
+            "The second line, undivided, shows its subject full of apprehension and appealing for \
+            sympathy and help. Late at night hostile measures may be taken against him, but he \
+            need not be anxious about them.",
+            "The third line, undivided, shows its subject about to advance with strength in his \
+            cheekbones. There will be evil. But the superior man, bent on cutting off the \
+            culprit, will walk alone and encounter the rain, till he be hated by his proper \
+            associates as if he were contaminated by the others. In the end there will be no \
+            blame against him.",
+            "The fourth line, undivided, shows one from whose buttocks the skin has been stripped, \
+            and who walks slowly and with difficulty. If he could act like a sheep led after its \
+            companions, occasion for repentance would disappear. But though he hear these words, \
+            he will not believe them.",
+            "The fifth line, undivided, shows the small men like a bed of purslane, which ought to \
+            be uprooted with the utmost determination. The subject of the line having such \
+            determination, his action, in harmony with his central position, will lead to no \
+            error or blame.",
+            "The sixth line, divided, shows its subject without any helpers on whom to call. His \
+            end will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 44,
+        judgment: "Gou shows a female who is bold and strong. It will not be good to marry such a \
+            female.",
+        image: "The trigram for the sky and below it that for wind form Gou. The sovereign, in \
+            accordance with this, delivers his charges, and promulgates his announcements \
+            throughout the four quarters of the kingdom.",
+        lines: [
+            "The first line, divided, shows how its subject should be kept like a carriage tied \
+            and fastened to a metal drag, in which case with firm correctness there will be good \
+            fortune. If he move in any direction, evil will appear. He will be like a lean pig \
+            which is sure to keep jumping about.",
+            "The second line, undivided, shows its subject with a wallet of fish. There will be no \
+            error. But it will not be well to let the subject of the first line go forward to the \
+            guests.",
+            "The third line, undivided, shows one from whose buttocks the skin has been stripped \
+            so that he walks with difficulty. He is in a perilous condition, but there will be no \
+            great error.",
+            "The fourth line, undivided, shows its subject with his wallet, but no fish in it. \
+            This will give rise to evil.",
+            "The fifth line, undivided, shows a medlar tree overspreading the gourd beneath it. If \
+            the subject of the line keep his brilliant qualities concealed, a good issue will \
+            descend as if from Heaven.",
+            "The sixth line, undivided, shows its subject receiving others on his horns. There \
+            will be occasion for regret, but there will be no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 45,
+        judgment: "In the state denoted by Cui, the king will repair to his ancestral temple. It \
+            will be advantageous also to meet with the great man; and then there will be progress \
+            and success, though the advantage must come through firm correctness. The use of \
+            great victims will conduce to good fortune; and in whatever direction movement is \
+            made, it will be advantageous.",
+        image: "The trigram representing the earth and that for the waters of a marsh raised \
+            above it form Cui. The superior man, in accordance with this, has his weapons of war \
+            put in good repair, to be prepared against unforeseen contingencies.",
+        lines: [
+            "The first line, divided, shows its subject with a sincere desire for union, but \
+            unable to carry it out, so that disorder is brought into the sphere of his union. If \
+            he cry out for help to his proper correlate, all at once his tears will give place to \
+            smiles. He need not mind the temporary difficulty; as he goes forward, there will be \
+            no error.",
+            "The second line, divided, shows its subject led forward by his correlate. There will \
+            be good fortune, and freedom from error. There is entire sincerity, and in that case \
+            even the small offerings of the vernal sacrifice are acceptable.",
+            "The third line, divided, shows its subject striving after union and seeming to sigh, \
+            yet nowhere finding any advantage. If he go forward, he will not err, though there \
+            may be some small cause for regret.",
+            "The fourth line, undivided, shows its subject in such a state that, if he be greatly \
+            fortunate, he will receive no blame.",
+            "The fifth line, undivided, shows the union of all under its subject in the place of \
+            dignity. There will be no error. If any do not have confidence in him, let him see to \
+            it that his virtue be great, long-continued, and firmly correct, and all occasion for \
+            repentance will disappear.",
+            "The topmost line, divided, shows its subject sighing and weeping; but there will be \
+            no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 46,
+        judgment: "Sheng indicates that under its conditions there will be great progress and \
+            success. Seeking by the qualities implied in it to meet with the great man, its \
+            subject need have no anxiety. Advance to the south will be fortunate.",
+        image: "The trigram representing the earth and that for wood growing in the midst of it \
+            form Sheng. The superior man, in accordance with this, pays careful attention to his \
+            virtue, and accumulates the small developments of it till it is high and great.",
+        lines: [
+            "The first line, divided, shows its subject advancing upwards with the welcome of \
+            those above him. There will be great good fortune.",
+            "The second line, undivided, shows its subject with that sincerity which will make \
+            even the small offerings of the vernal sacrifice acceptable. There will be no error.",
+            "The third line, undivided, shows its subject ascending upwards as into an empty city.",
+            "The fourth line, divided, shows its subject employed by the king to present his \
+            offerings on mount Qi. There will be good fortune; there will be no error.",
+            "The fifth line, divided, shows its subject firmly correct, and therefore enjoying \
+            good fortune. He ascends the stairs with all due ceremony.",
+            "The sixth line, divided, shows its subject advancing upwards blindly. Advantage will \
+            be found in a ceaseless maintenance of firm correctness.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 47,
+        judgment: "In the condition denoted by Kun there may yet be progress and success. For the \
+            firm and correct, the really great man, there will be good fortune. He will fall into \
+            no error. If he make speeches, his words cannot be made good.",
+        image: "The trigram representing a marsh, and below it that for a defile, which has \
+            drained the other dry so that there is no water in it, form Kun. The superior man, in \
+            accordance with this, will sacrifice his life in order to carry out his purpose.",
+        lines: [
+            "The first line, divided, shows its subject with bare buttocks straitened under the \
+            stump of a tree. He enters a dark valley, and for three years has no prospect of \
+            deliverance.",
+            "The second line, undivided, shows its subject straitened amidst his wine and viands. \
+            There come to him anon the red knee-covers of the ruler. It will be well for him to \
+            maintain his sincerity as in sacrificing. Active operations on his part will lead to \
+            evil, but he will be free from blame.",
+            "The third line, divided, shows its subject straitened before a frowning rock. He lays \
+            hold of thorns. He enters his palace, and does not see his wife. There will be evil.",
+            "The fourth line, undivided, shows its subject proceeding very slowly to help the \
+            subject of the first line, who is straitened by the metal carriage in front of him. \
+            There will be occasion for regret, but the end will be good.",
+            "The fifth line, undivided, shows its subject with his nose and feet cut off. He is \
+            straitened by his ministers in their scarlet aprons. He is leisurely in his \
+            movements, however, and is satisfied. It will be well for him to be as sincere as in \
+            sacrificing to spiritual beings.",
+            "The sixth line, divided, shows its subject straitened, as if bound with creepers, or \
+            in a high and dangerous position, and saying to himself, 'If I move, I shall repent \
+            it.' If he do repent of former errors, there will be good fortune in his going \
+            forward.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 48,
+        judgment: "Looking at Jing, we think of how the site of a town may be changed, while the \
+            fashion of its wells undergoes no change. The water of a well never disappears and \
+            never receives any great increase, and those who come and those who go draw and enjoy \
+            the benefit. If the drawing have nearly been accomplished, but, before the rope has \
+            quite reached the water, the bucket is broken, this is evil.",
+        image: "The trigram representing wood, and above it that for water, form Jing. The \
+            superior man, in accordance with this, comforts the people, and stimulates them to \
+            mutual helpfulness.",
+        lines: [
+            "The first line, divided, shows a well so muddy that men will not drink of it; or an \
+            old well to which neither birds nor other creatures resort.",
+            "The second line, undivided, shows a well from which by a hole the water escapes and \
+            flows away to the shrimps and such small creatures among the grass, or the water \
+            leaks away from a broken basket.",
+            "The third line, undivided, shows a well which has been cleared out, but is not used. \
+            Our hearts are sorry for this, for the water might be drawn out and used. If the king \
+            were only intelligent, both he and others might receive the benefit of it.",
+            "The fourth line, divided, shows a well, the lining of which is well laid. There will \
+            be no error.",
+            "The fifth line, undivided, shows a clear, limpid well, the waters from whose cold \
+            spring are freely drunk.",
+            "The topmost line, divided, shows the water from the well brought to the top, which is \
+            not allowed to be covered. This suggests the idea of sincerity. There will be great \
+            good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 49,
+        judgment: "What takes place as indicated by Ge is believed in only after it has been \
+            accomplished. There will be great progress and success. Advantage will come from \
+            being firm and correct. In that case occasion for repentance will disappear.",
+        image: "The trigram representing the waters of a marsh and that for fire in the midst of \
+            them form Ge. The superior man, in accordance with this, regulates his astronomical \
+            calculations, and makes clear the seasons and times.",
+        lines: [
+            "The first line, undivided, shows its subject as if he were bound with the skin of a \
+            yellow ox.",
+            "The second line, divided, shows its subject making his changes after some time has \
+            passed. Action taken will be fortunate. There will be no error.",
+            "The third line, undivided, shows that action taken by its subject will be evil. \
+            Though he be firm and correct, his position is perilous. If the change he \
+            contemplates have been three times fully discussed, he will be believed in.",
+            "The fourth line, undivided, shows occasion for repentance disappearing from its \
+            subject. Let him be believed in; and though he change existing ordinances, there will \
+            be good fortune.",
+            "The fifth line, undivided, shows the great man producing his changes as the tiger \
+            does when he changes his stripes. Before he divines and proceeds to action, faith has \
+            been reposed in him.",
+            "The sixth line, divided, shows the superior man producing his changes as the leopard \
+            does when he changes his spots, while small men change their faces and show their \
+            obedience. To go forward now would lead to evil, but there will be good fortune in \
+            abiding firm and correct.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 50,
+        judgment: "Ding gives the intimation of great progress and success.",
+        image: "The trigram representing wood and above it that for fire form Ding. The superior \
+            man, in accordance with this, keeps his every position correct, and maintains secure \
+            the appointment of Heaven.",
+        lines: [
+            "The first line, divided, shows the cauldron overthrown and its feet turned up. But \
+            there will be advantage in its getting rid of what was bad in it. It shows also the \
+            concubine whose position is improved by means of her son. There will be no error.",
+            "The second line, undivided, shows the cauldron with the things to be cooked in it. If \
+            its subject can say, 'My enemy dislikes me, but he cannot approach me,' there will be \
+            good fortune.",
+            "The third line, undivided, shows the cauldron with the places of its ears changed. \
+            The progress of its subject is thereby stopped. The fat flesh of the pheasant which \
+            is in the cauldron will not be eaten. But the genial rain will come, and the grounds \
+            for repentance will disappear. There will be good fortune in the end.",
+            "The fourth line, undivided, shows the cauldron with its feet broken; and its \
+            contents, designed for the ruler's use, overturned and spilt. Its subject will be \
+            made to blush for shame. There will be evil.",
+            "The fifth line, divided, shows the cauldron with yellow ears and rings of metal in \
+            them. There will be advantage through being firm and correct.",
+            "The sixth line, undivided, shows the cauldron with rings of jade. There will be great \
+            good fortune, and all action taken will be in every way advantageous.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 51,
+        judgment: "Zhen gives the intimation of ease and development. When the time of movement \
+            which it indicates comes, the subject of the hexagram will be found looking out with \
+            apprehension, and yet smiling and talking cheerfully. When the movement like a crash \
+            of thunder terrifies all within a hundred li, he will be like the sincere worshipper \
+            who is not startled into letting go his ladle and cup of sacrificial spirits.",
+        image: "The thunder being repeated forms Zhen. The superior man, in accordance with this, \
+            is fearful and apprehensive, cultivates his virtue, and examines his faults.",
+        lines: [
+            "The first line, undivided, shows its subject, when the movement approaches, looking \
+            out and around with apprehension, and afterwards smiling and talking cheerfully. \
+            There will be good fortune.",
+            "The second line, divided, shows its subject, when the movement approaches, in a \
+            position of peril. He judges it better to let go the articles in his possession, and \
+            to ascend a very lofty height. There is no occasion for him to pursue after the \
+            things he has let go; in seven days he will find them.",
+            "The third line, divided, shows its subject distraught amid the startling movements \
+            going on. If those movements excite him to right action, there will be no mistake.",
+            "The fourth line, undivided, shows its subject, amid the startling movements, supinely \
+            sinking deeper in the mud.",
+            "The fifth line, divided, shows its subject going and coming amidst the startling \
+            movements of the time, and always in peril; but perhaps he will not incur loss, and \
+            find business which he can accomplish.",
+            "The topmost line, divided, shows its subject, amidst the startling movements of the \
+            time, in breathless dismay and looking round him with trembling apprehension. If he \
+            take action, there will be evil. If, while the startling movements have not reached \
+            his own person and his neighbourhood, he were to take precautions, there would be no \
+            error, though his relatives might still speak against him.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 52,
+        judgment: "When one's resting is like that of the back, and he loses all consciousness of \
+            self; when he walks in his courtyard, and does not see any of the persons in it, \
+            there will be no error.",
+        image: "Two trigrams representing a mountain, one over the other, form Gen. The superior \
+            man, in accordance with this, does not go in his thoughts beyond the duties of the \
+            position in which he is.",
+        lines: [
+            "The first line, divided, shows its subject keeping his toes at rest. There will be no \
+            error; but it will be advantageous for him to be persistently firm and correct.",
+            "The second line, divided, shows its subject keeping the calves of his legs at rest. \
+            He cannot help the subject of the line above whom he follows, and is dissatisfied in \
+            his mind.",
+            "The third line, undivided, shows its subject keeping his loins at rest, and \
+            separating the ribs from the body below. The situation is perilous, and the heart \
+            glows with suppressed excitement.",
+            "The fourth line, divided, shows its subject keeping his trunk at rest. There will be \
+            no error.",
+            "The fifth line, divided, shows its subject keeping his jawbones at rest, so that his \
+            words are all orderly. Occasion for repentance will disappear.",
+            "The sixth line, undivided, shows its subject devotedly maintaining his restfulness. \
+            There will be good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 53,
+        judgment: "Jian suggests to us the marriage of a young lady, and the good fortune \
+            attending it. There will be advantage in being firm and correct.",
+        image: "The trigram representing a mountain, and above it that for a tree, form Jian. The \
+            superior man, in accordance with this, attains to and abides in extraordinary virtue, \
+            and makes the manners of the people good.",
+        lines: [
+            "The first line, divided, shows the wild geese gradually approaching the shore. A \
+            young officer in similar circumstances will be in a position of danger, and be spoken \
+            against; but there will be no error.",
+            "The second line, divided, shows the geese gradually approaching the large rocks, \
+            where they eat and drink joyfully and at ease. There will be good fortune.",
+            "The third line, undivided, shows them gradually advancing to the dry plains. It \
+            suggests also the idea of a husband who goes on an expedition and does not return, \
+            and of a wife who is pregnant, but will not nourish her child. There will be evil. \
+            The case symbolised might be advantageous in resisting plunderers.",
+            "The fourth line, divided, shows the geese gradually advancing to the trees. They may \
+            light on the flat branches. There will be no error.",
+            "The fifth line, undivided, shows the geese gradually advancing to the high mound. It \
+            suggests the idea of a wife who for three years does not become pregnant; but in the \
+            end the natural issue cannot be prevented. There will be good fortune.",
+            "The sixth line, undivided, shows the geese gradually advancing to the large heights \
+            beyond. Their feathers can be used as ornaments. There will be good fortune.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 54,
+        judgment: "Gui Mei indicates that under the conditions which it denotes action will be \
+            evil, and in no wise advantageous.",
+        image: "The trigram representing the waters of a marsh and over it that for thunder form \
+            Gui Mei. The superior man, in accordance with this, having regard to the far-distant \
+            end, knows the mischief that may be done at the beginning.",
+        lines: [
+            "The first line, undivided, shows the younger sister married off in a position \
+            ancillary to the real wife. It suggests the idea of a person lame on one leg who yet \
+            manages to tramp along. Going forward will be fortunate.",
+            "The second line, undivided, shows her blind of one eye, and yet able to see. There \
+            will be advantage in her maintaining the firm correctness of a solitary widow.",
+            "The third line, divided, shows the younger sister who was to be married off in a mean \
+            position. She returns and accepts an ancillary position.",
+            "The fourth line, undivided, shows the younger sister who is to be married off \
+            protracting the time. She may be late in being married, but the time will come.",
+            "The fifth line, divided, reminds us of the marrying of the younger sister of king Di \
+            Yi. The sleeves of her as the principal wife were not equal to those of the still \
+            younger sister who accompanied her in an inferior capacity. The moon almost full \
+            suggests the same lesson. There will be good fortune.",
+            "The sixth line, divided, shows the young lady bearing the basket, but without \
+            anything in it, and the young man slaughtering the sheep, but without blood flowing \
+            from it. There will be no advantage in any way.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 55,
+        judgment: "Feng intimates progress and development. When a king has reached the point \
+            which the name denotes, there is no occasion to be anxious through fear of a change. \
+            Let him be as the sun at noon.",
+        image: "The trigrams representing thunder and lightning combined form Feng. The superior \
+            man, in accordance with this, decides cases of litigation, and apportions punishments \
+            with exactness.",
+        lines: [
+            "The first line, undivided, shows its subject meeting with his mate. Though they are \
+            both of the same character, there will be no error. Advance will call forth approval.",
+            "The second line, divided, shows its subject surrounded by screens so large and thick \
+            that at midday he can see from them the constellation of the Bushel. If he go and try \
+            to enlighten his ruler who is so beclouded, he will make himself to be viewed with \
+            suspicion and dislike. Let him cherish his feeling of sincere devotion that he may \
+            thereby move his ruler's mind, and there will be good fortune.",
+            "The third line, undivided, shows its subject with an additional screen of a large and \
+            thick banner, through which at midday he can see the small Mei star. In the darkness \
+            he breaks his right arm; but there will be no error.",
+            "The fourth line, undivided, shows its subject in a tent so large and thick that at \
+            midday he can see from it the constellation of the Bushel. But he meets with the \
+            subject of the first line, undivided like himself. There will be good fortune.",
+            "The fifth line, divided, shows its subject bringing around him the men of brilliant \
+            ability. There will be occasion for congratulation and praise. There will be good \
+            fortune.",
+            "The sixth line, divided, shows its subject with his house made large, but only \
+            serving as a screen to his household. When he looks at his door, it is still, and \
+            there is nobody about it. For three years no one is to be seen. There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 56,
+        judgment: "Lü intimates that in the condition which it denotes there may be some little \
+            attainment and progress. If the stranger or traveller be firm and correct as he ought \
+            to be, there will be good fortune.",
+        image: "The trigram representing a mountain and above it that for fire form Lü. The \
+            superior man, in accordance with this, exercises his wisdom and caution in the use of \
+            punishments, and does not allow litigations to continue.",
+        lines: [
+            "The first line, divided, shows the stranger mean and meanly occupied. It is thus that \
+            he brings on himself further calamity.",
+            "The second line, divided, shows the stranger, occupying his lodging-house, carrying \
+            his means of livelihood about him, and provided with good and trusty servants.",
+            "The third line, undivided, shows the stranger, burning his lodging-house, and having \
+            lost his servants. However firm and correct he try to be, he will be in peril.",
+            "The fourth line, undivided, shows the traveller in a resting-place, having also the \
+            means of livelihood and the axe, but still saying, 'I am not at ease in my mind.'",
+            "The fifth line, divided, shows its subject shooting a pheasant. He will lose his \
+            arrow, but in the end he will obtain praise and a high charge.",
+            "The sixth line, undivided, suggests the idea of a bird burning its nest. The \
+            stranger, thus represented, first laughs and then cries out. He has lost his ox-like \
+            docility too easily and readily. There will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 57,
+        judgment: "Xun intimates that under the conditions which it denotes there will be some \
+            little attainment and progress. There will be advantage in movement onward in \
+            whatever direction. It will be advantageous also to see the great man.",
+        image: "Two trigrams representing wind, following each other, form Xun. The superior man, \
+            in accordance with this, reiterates his orders, and secures the practice of his \
+            affairs.",
+        lines: [
+            "The first line, divided, shows its subject now advancing, now receding. It would be \
+            advantageous for him to have the firm correctness of a brave soldier.",
+            "The second line, undivided, shows the representative of Xun beneath a couch, and \
+            employing diviners and exorcists in a way bordering on confusion. There will be good \
+            fortune and no error.",
+            "The third line, undivided, shows its subject penetrating only by violent and repeated \
+            efforts. There will be occasion for regret.",
+            "The fourth line, divided, shows all occasion for repentance in its subject passed \
+            away. He takes game for its threefold use in his hunting.",
+            "The fifth line, undivided, shows that with firm correctness there will be good \
+            fortune to its subject. All occasion for repentance will disappear, and all his \
+            movements will be advantageous. There may have been no good beginning, but there will \
+            be a good end. Three days before making any change, let him give notice of it; and \
+            three days after, let him reconsider it. There will thus be good fortune.",
+            "The sixth line, undivided, shows the representative of penetration beneath a couch, \
+            and having lost the axe with which he executed his decisions. However firm and \
+            correct he may try to be, there will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 58,
+        judgment: "Dui intimates that under its conditions there will be progress and attainment. \
+            But it will be advantageous to be firm and correct.",
+        image: "Two symbols representing the waters of a marsh, one over the other, form Dui. The \
+            superior man, in accordance with this, encourages the conversation of friends and the \
+            stimulus of their common practice.",
+        lines: [
+            "The first line, undivided, shows the pleasure of inward harmony. There will be good \
+            fortune.",
+            "The second line, undivided, shows the pleasure arising from inward sincerity. There \
+            will be good fortune. Occasion for repentance will disappear.",
+            "The third line, divided, shows its subject bringing round himself whatever can give \
+            pleasure. There will be evil.",
+            "The fourth line, undivided, shows its subject deliberating about what to seek his \
+            pleasure in, and not at rest. He borders on what would be injurious, but there will \
+            be cause for joy.",
+            "The fifth line, undivided, shows its subject trusting in one who would injure him. \
+            The situation is perilous.",
+            "The sixth line, divided, shows the pleasure of its subject in leading and attracting \
+            others.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 59,
+        judgment: "Huan intimates that under its conditions there will be progress and success. \
+            The king goes to his ancestral temple; and it will be advantageous to cross the great \
+            stream. It will be advantageous to be firm and correct.",
+        image: "The trigram representing water and that for wind moving above the water form \
+            Huan. The ancient kings, in accordance with this, presented offerings to God and \
+            established the ancestral temple.",
+        lines: [
+            "The first line, divided, shows its subject engaged in rescuing from the impending \
+            evil and having the assistance of a strong horse. There will be good fortune.",
+            "The second line, undivided, shows its subject, amid the dispersion, hurrying to his \
+            contrivance for security. All occasion for repentance will disappear.",
+            "The third line, divided, shows its subject discarding any regard to his own person. \
+            There will be no occasion for repentance.",
+            "The fourth line, divided, shows its subject scattering the various parties in the \
+            state. There will be great good fortune. From the dispersion he collects again good \
+            men standing out, a crowd like a mound, which is what ordinary men would not have \
+            thought of.",
+            "The fifth line, undivided, shows its subject amidst the dispersion issuing his great \
+            announcements as the perspiration flows from his body. He scatters abroad also the \
+            accumulations in the royal granaries. There will be no error.",
+            "The topmost line, undivided, shows its subject disposing of what may be called its \
+            bloody wounds, and going and separating himself from its anxious fears. There will be \
+            no error.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 60,
+        judgment: "Jie intimates that under its conditions there will be progress and attainment. \
+            But if the regulations which it prescribes be severe and difficult, they cannot be \
+            permanent.",
+        image: "The trigram representing a lake, and above it that for water, form Jie. The \
+            superior man, in accordance with this, constructs his methods of numbering and \
+            measurement, and discusses points of virtue and conduct.",
+        lines: [
+            "The first line, undivided, shows its subject not quitting the courtyard outside his \
+            door. There will be no error.",
+            "The second line, undivided, shows its subject not quitting the courtyard inside his \
+            gate. There will be evil.",
+            "The third line, divided, shows its subject with no appearance of observing the proper \
+            regulations, in which case we shall see him lamenting. But there will be no one to \
+            blame but himself.",
+            "The fourth line, divided, shows its subject quietly and naturally attentive to all \
+            regulations. There will be progress and success.",
+            "The fifth line, undivided, shows its subject sweetly and acceptably enacting his \
+            regulations. There will be good fortune. The onward progress with them will afford \
+            ground for admiration.",
+            "The sixth line, divided, shows its subject enacting regulations severe and difficult. \
+            Even with firmness and correctness there will be evil. But though there will be cause \
+            for repentance, it will by and by disappear.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 61,
+        judgment: "Zhong Fu moves even pigs and fish, and leads to good fortune. There will be \
+            advantage in crossing the great stream. There will be advantage in being firm and \
+            correct.",
+        image: "The trigram representing the waters of a marsh and that for wind above them form \
+            Zhong Fu. The superior man, in accordance with this, deliberates about cases of \
+            litigation, and delays the infliction of death.",
+        lines: [
+            "The first line, undivided, shows its subject resting in himself. There will be good \
+            fortune. If he sought to any other, he would not find rest.",
+            "The second line, undivided, shows its subject like the crane crying out in her hidden \
+            retirement, and her young ones responding to her. It is as if it were said, 'I have a \
+            cup of good spirits,' and the response were, 'I will partake of it with you.'",
+            "The third line, divided, shows its subject having met with his mate. Now he beats his \
+            drum, and now he leaves off. Now he weeps, and now he sings.",
+            "The fourth line, divided, shows its subject like the moon nearly full, and like a \
+            horse in a team the fellow of which disappears. There will be no error.",
+            "The fifth line, undivided, shows its subject perfectly sincere, and linking others to \
+            him in closest union. There will be no error.",
+            "The sixth line, undivided, shows its subject in chanticleer trying to mount to \
+            heaven. Even with firmness and correctness there will be evil.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 62,
+        judgment: "Xiao Guo indicates that in the circumstances which it implies there will be \
+            progress and attainment. But it will be advantageous to be firm and correct. What the \
+            name denotes may be done in small affairs, but not in great affairs. It is like the \
+            notes that come down from a bird on the wing; to descend is better than to ascend. \
+            There will in this way be great good fortune.",
+        image: "The trigram representing a mountain and that for thunder above it form Xiao Guo. \
+            The superior man, in accordance with this, in his conduct exceeds in humility, in \
+            mourning exceeds in sorrow, and in his expenditure exceeds in economy.",
+        lines: [
+            "The first line, divided, suggests the idea of a bird flying, and ascending till the \
+            issue is evil.",
+            "The second line, divided, shows its subject passing by his grandfather, and meeting \
+            with his grandmother; not attempting anything against his ruler, but meeting him as \
+            his minister. There will be no error.",
+            "The third line, undivided, shows its subject taking no extraordinary precautions \
+            against danger; and some in consequence finding opportunity to assail and injure him. \
+            There will be evil.",
+            "The fourth line, undivided, shows its subject falling into no error, but without \
+            exceeding the proper course, meeting the exigency of his situation. Going forward \
+            will be dangerous, and he must be cautious. There is no occasion to be using firmness \
+            perpetually.",
+            "The fifth line, divided, suggests the idea of dense clouds, but no rain, coming from \
+            our borders in the west. It also shows the prince shooting his arrow, and taking the \
+            bird in a cave.",
+            "The sixth line, divided, shows its subject not meeting the exigency of his situation, \
+            and exceeding the proper course. It suggests the idea of a bird flying far aloft. \
+            There will be evil. The case is what is called one of calamity and self-produced \
+            injury.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 63,
+        judgment: "Ji Ji intimates progress and success in small matters. There will be advantage \
+            in being firm and correct. There has been good fortune in the beginning; there may be \
+            disorder in the end.",
+        image: "The trigram representing fire and that for water above it form Ji Ji. The \
+            superior man, in accordance with this, thinks of evil that may come, and beforehand \
+            guards against it.",
+        lines: [
+            "The first line, undivided, shows its subject as a driver who drags back his wheel, or \
+            as a fox which has wet his tail. There will be no error.",
+            "The second line, divided, shows its subject as a wife who has lost her \
+            carriage-screen. There is no occasion to go in pursuit of it. In seven days she will \
+            find it.",
+            "The third line, undivided, suggests the case of Gao Zong who attacked the Demon \
+            region, but was three years in subduing it. Small men should not be employed in such \
+            enterprises.",
+            "The fourth line, divided, shows its subject with rags provided against any leak in \
+            his boat, and on his guard all day long.",
+            "The fifth line, undivided, shows its subject as the neighbour in the east who \
+            slaughters an ox for his sacrifice; but this is not equal to the small spring \
+            sacrifice of the neighbour in the west, whose sincerity receives the blessing.",
+            "The topmost line, divided, shows its subject with even his head immersed. The \
+            position is perilous.",
+        ],
+        all_lines: None,
+    },
+    HexagramText {
+        number: 64,
+        judgment: "Wei Ji intimates progress and success in the circumstances which it implies. \
+            We see a young fox that has nearly crossed the stream, when its tail gets immersed. \
+            There will be no advantage in any way.",
+        image: "The trigram representing water and that for fire above it form Wei Ji. The \
+            superior man, in accordance with this, carefully discriminates among the qualities of \
+            things, and the existence of each in its proper place.",
+        lines: [
+            "The first line, divided, shows its subject like a fox whose tail is immersed. There \
+            will be occasion for regret.",
+            "The second line, undivided, shows its subject dragging back his carriage-wheel. With \
+            firmness and correctness there will be good fortune.",
+            "The third line, divided, shows its subject, with the state of things not yet \
+            remedied, advancing on; which will be evil. But there will be advantage in trying to \
+            cross the great stream.",
+            "The fourth line, undivided, shows its subject by firm correctness obtaining good \
+            fortune, so that all occasion for repentance disappears. Let him stir himself up, as \
+            if he were invading the Demon region, where for three years rewards will come to him \
+            and his troops from the great kingdom.",
+            "The fifth line, divided, shows its subject by firm correctness obtaining good \
+            fortune, and having no occasion for repentance. We see in him the brightness of a \
+            superior man, and the possession of sincerity. There will be good fortune.",
+            "The topmost line, undivided, shows its subject full of confidence and therefore \
+            feasting quietly. There will be no error. If he cherish this confidence till he is \
+            like the fox who gets his head immersed, it will not be right.",
+        ],
+        all_lines: None,
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Verifies that every hexagram has a complete set of texts, stored in order.
+    #[test]
+    fn test_all_texts_present() {
+        for (i, text) in HEXAGRAM_TEXTS.iter().enumerate() {
+            assert_eq!(text.number as usize, i + 1);
+            assert!(!text.judgment.is_empty());
+            assert!(!text.image.is_empty());
+            assert!(text.lines.iter().all(|line| !line.is_empty()));
+            assert_eq!(text.all_lines.is_some(), text.number <= 2);
+        }
+    }
+
+    /// Verifies looking up the texts by hexagram number.
+    #[test]
+    fn test_lookup_by_number() {
+        assert_eq!(hexagram_text(1).unwrap().number, 1);
+        assert_eq!(hexagram_text(64).unwrap().number, 64);
+        assert!(hexagram_text(0).is_none());
+        assert!(hexagram_text(65).is_none());
+        assert!(hexagram_text(30).unwrap().judgment.starts_with("Li"));
+    }
+}